@@ -0,0 +1,24 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use ::approx::AbsDiffEq;
+
+use crate::Duration;
+
+/// Compares durations using [`Duration::approx_eq`].
+///
+/// The default epsilon is zero, and comparisons involving `None` are always
+/// `false`.
+#[cfg_attr(docsrs, doc(cfg(feature = "approx")))]
+impl AbsDiffEq for Duration {
+    type Epsilon = Self;
+
+    #[inline]
+    fn default_epsilon() -> Self::Epsilon {
+        Self::ZERO
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.approx_eq(*other, epsilon)
+    }
+}
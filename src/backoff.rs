@@ -0,0 +1,91 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::iter::FusedIterator;
+
+use crate::Duration;
+
+/// An iterator over backoff delays, such as for retrying a failed operation.
+///
+/// The delays grow exponentially or linearly from a base delay until they
+/// reach the cap, and then stay at the cap forever. Growth that would
+/// overflow is also clamped to the cap, so this never panics.
+///
+/// The iterator yields nothing if any of the given durations is `None`.
+///
+/// # Examples
+///
+/// ```
+/// use easytime::{Backoff, Duration};
+///
+/// let ms = Duration::from_millis;
+/// let delays: Vec<_> = Backoff::exponential(ms(100), ms(1_000)).take(6).collect();
+/// assert_eq!(delays, [ms(100), ms(200), ms(400), ms(800), ms(1_000), ms(1_000)]);
+///
+/// let delays: Vec<_> = Backoff::linear(ms(100), ms(300), ms(1_000)).take(5).collect();
+/// assert_eq!(delays, [ms(100), ms(400), ms(700), ms(1_000), ms(1_000)]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Backoff {
+    /// The next delay to yield before capping, or `None` if the iterator is
+    /// exhausted.
+    next: Duration,
+    /// The increment for linear backoff, or `None` for exponential backoff.
+    step: Option<Duration>,
+    cap: Duration,
+}
+
+impl Backoff {
+    /// Creates a new `Backoff` that yields `base`, `base * 2`, `base * 4`, and
+    /// so on, capped at `cap`.
+    ///
+    /// If `base` is zero, this yields zero forever.
+    #[must_use]
+    pub const fn exponential(base: Duration, cap: Duration) -> Self {
+        Self::with_step(base, None, cap)
+    }
+
+    /// Creates a new `Backoff` that yields `base`, `base + step`,
+    /// `base + step * 2`, and so on, capped at `cap`.
+    #[must_use]
+    pub const fn linear(base: Duration, step: Duration, cap: Duration) -> Self {
+        Self::with_step(base, Some(step), cap)
+    }
+
+    const fn with_step(base: Duration, step: Option<Duration>, cap: Duration) -> Self {
+        let next = match step {
+            Some(step) if step.is_none() => Duration::NONE,
+            _ if cap.is_none() => Duration::NONE,
+            _ => base,
+        };
+        Self { next, step, cap }
+    }
+}
+
+impl Iterator for Backoff {
+    type Item = Duration;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next.is_none() {
+            return None;
+        }
+        let current = if self.next < self.cap { self.next } else { self.cap };
+        let grown = match self.step {
+            Some(step) => current + step,
+            None => current * 2,
+        };
+        // `current` is less than or equal to `cap`, so an overflow means that
+        // the next delay would exceed `cap` anyway.
+        self.next = if grown.is_some() { grown } else { self.cap };
+        Some(current)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.next.is_none() {
+            (0, Some(0))
+        } else {
+            (usize::MAX, None)
+        }
+    }
+}
+
+impl FusedIterator for Backoff {}
@@ -0,0 +1,44 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::time;
+
+use ::borsh::{io, BorshDeserialize, BorshSerialize};
+
+use crate::{duration::NANOS_PER_SEC, Duration};
+
+/// Serializes `Duration` in the following layout:
+///
+/// | value     | encoding                                                             |
+/// | --------- | -------------------------------------------------------------------- |
+/// | `None`    | `0u8`                                                                |
+/// | `Some(d)` | `1u8`, then `d.as_secs()` as `u64`, then `d.subsec_nanos()` as `u32` |
+///
+/// All integers are little-endian. This is the same as borsh's encoding of
+/// `Option<(u64, u32)>`, and this layout will not change in semver-compatible
+/// releases.
+#[cfg_attr(docsrs, doc(cfg(feature = "borsh")))]
+impl BorshSerialize for Duration {
+    fn serialize<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        self.0.map(|d| (d.as_secs(), d.subsec_nanos())).serialize(writer)
+    }
+}
+
+/// Deserializes `Duration` from the layout described in the
+/// [`BorshSerialize`] implementation.
+///
+/// Returns an error if the nanoseconds part is not less than one billion.
+#[cfg_attr(docsrs, doc(cfg(feature = "borsh")))]
+impl BorshDeserialize for Duration {
+    fn deserialize_reader<R: io::Read>(reader: &mut R) -> io::Result<Self> {
+        match Option::<(u64, u32)>::deserialize_reader(reader)? {
+            None => Ok(Self::NONE),
+            Some((secs, nanos)) if nanos < NANOS_PER_SEC => {
+                Ok(Self(Some(time::Duration::new(secs, nanos))))
+            }
+            Some(_) => Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "nanoseconds part of Duration must be less than one billion",
+            )),
+        }
+    }
+}
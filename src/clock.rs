@@ -0,0 +1,65 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::time;
+
+use crate::Instant;
+
+/// A source of the current time.
+///
+/// Code that needs the current time can be generic over `C: Clock` instead of
+/// calling [`Instant::now`] directly, so that tests can swap in a clock that
+/// advances deterministically.
+///
+/// # Examples
+///
+/// ```
+/// use easytime::{Clock, Duration, Instant, SystemClock};
+///
+/// fn deadline<C: Clock>(clock: &C, timeout: Duration) -> Instant {
+///     clock.now_instant() + timeout
+/// }
+///
+/// assert!(deadline(&SystemClock, Duration::from_secs(1)) > Instant::now());
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub trait Clock {
+    /// Returns an instant corresponding to "now".
+    #[must_use]
+    fn now_instant(&self) -> Instant;
+
+    /// Returns the system time corresponding to "now".
+    #[must_use]
+    fn now_system(&self) -> time::SystemTime;
+}
+
+impl<C: ?Sized + Clock> Clock for &C {
+    #[inline]
+    fn now_instant(&self) -> Instant {
+        (**self).now_instant()
+    }
+
+    #[inline]
+    fn now_system(&self) -> time::SystemTime {
+        (**self).now_system()
+    }
+}
+
+/// A [`Clock`] that reads the operating system's clocks.
+///
+/// This delegates to [`Instant::now`] and [`std::time::SystemTime::now`].
+#[derive(Debug, Clone, Copy, Default)]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[allow(clippy::exhaustive_structs)] // Intentionally constructible as `SystemClock`.
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    #[inline]
+    fn now_instant(&self) -> Instant {
+        Instant::now()
+    }
+
+    #[inline]
+    fn now_system(&self) -> time::SystemTime {
+        time::SystemTime::now()
+    }
+}
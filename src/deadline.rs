@@ -0,0 +1,95 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use crate::{Duration, Instant};
+
+/// An absolute point in time by which an operation should complete, built on
+/// [`Instant`].
+///
+/// All arithmetic is performed using `easytime` types, so it never panics.
+/// If the deadline cannot be computed (e.g., the timeout overflows or is
+/// `None`), the deadline is treated as being infinitely far in the future:
+/// it never elapses and [`remaining`] returns [`Duration::NONE`].
+///
+/// # Examples
+///
+/// ```
+/// use easytime::{Deadline, Duration};
+///
+/// let deadline = Deadline::after(Duration::from_secs(1));
+/// assert!(!deadline.is_elapsed());
+/// assert!(deadline.remaining() <= Duration::from_secs(1));
+/// ```
+///
+/// [`remaining`]: Self::remaining
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub struct Deadline {
+    instant: Instant,
+}
+
+impl Deadline {
+    /// Creates a new `Deadline` at the specified instant.
+    #[must_use]
+    pub const fn at(instant: Instant) -> Self {
+        Self { instant }
+    }
+
+    /// Creates a new `Deadline` that is `timeout` after now.
+    #[must_use]
+    pub fn after(timeout: Duration) -> Self {
+        Self::at(Instant::after(timeout))
+    }
+
+    /// Returns the instant of this deadline.
+    #[must_use]
+    pub const fn instant(&self) -> Instant {
+        self.instant
+    }
+
+    /// Returns the amount of time remaining until this deadline, or zero
+    /// duration if it has already passed.
+    ///
+    /// Returns [`Duration::NONE`] if this deadline is `None`.
+    #[must_use]
+    pub fn remaining(&self) -> Duration {
+        self.remaining_at(Instant::now())
+    }
+
+    /// Returns the amount of time remaining from `now` until this deadline,
+    /// or zero duration if it has already passed.
+    ///
+    /// Returns [`Duration::NONE`] if this deadline or `now` is `None`.
+    #[must_use]
+    pub fn remaining_at(&self, now: Instant) -> Duration {
+        if self.instant.is_none() || now.is_none() {
+            return Duration::NONE;
+        }
+        self.instant - now
+    }
+
+    /// Returns `true` if this deadline has passed.
+    #[must_use]
+    pub fn is_elapsed(&self) -> bool {
+        self.is_elapsed_at(Instant::now())
+    }
+
+    /// Returns `true` if this deadline has passed as of `now`.
+    ///
+    /// Returns `false` if this deadline or `now` is `None`.
+    #[must_use]
+    pub fn is_elapsed_at(&self, now: Instant) -> bool {
+        self.instant.is_some() && now.is_some() && now >= self.instant
+    }
+
+    /// Returns the earlier of two deadlines.
+    ///
+    /// A `None` deadline never elapses, so the other deadline is returned.
+    #[must_use]
+    pub fn min(self, other: Self) -> Self {
+        match (self.instant.is_some(), other.instant.is_some()) {
+            (true, true) if other.instant < self.instant => other,
+            (false, true) => other,
+            _ => self,
+        }
+    }
+}
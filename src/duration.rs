@@ -4,13 +4,61 @@
 
 use core::{
     cmp, fmt,
-    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Rem, RemAssign, Sub, SubAssign},
     time,
 };
 
-use crate::{utils::pair_and_then, TryFromTimeError};
+use crate::{utils::pair_and_then, TryFromTimeError, TryFromTimeErrorKind};
 
-const NANOS_PER_SEC: u32 = 1_000_000_000;
+pub(crate) const NANOS_PER_SEC: u32 = 1_000_000_000;
+
+/// Creates a `time::Duration` from the total number of nanoseconds, or returns
+/// `None` if it is larger than `time::Duration::MAX`.
+pub(crate) const fn duration_from_nanos_u128(nanos: u128) -> Option<time::Duration> {
+    let secs = nanos / (NANOS_PER_SEC as u128);
+    if secs > u64::MAX as u128 {
+        return None;
+    }
+    Some(time::Duration::new(secs as u64, (nanos % (NANOS_PER_SEC as u128)) as u32))
+}
+
+const fn gcd_u128(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        let r = a % b;
+        a = b;
+        b = r;
+    }
+    a
+}
+
+fn checked_rem(lhs: time::Duration, rhs: time::Duration) -> Option<time::Duration> {
+    lhs.as_nanos().checked_rem(rhs.as_nanos()).and_then(duration_from_nanos_u128)
+}
+
+const fn concat_bytes(secs: [u8; 8], nanos: [u8; 4]) -> [u8; 12] {
+    let mut bytes = [0; 12];
+    let mut i = 0;
+    while i < 8 {
+        bytes[i] = secs[i];
+        i += 1;
+    }
+    while i < 12 {
+        bytes[i] = nanos[i - 8];
+        i += 1;
+    }
+    bytes
+}
+
+const fn split_bytes(b: [u8; 12]) -> ([u8; 8], [u8; 4]) {
+    ([b[0], b[1], b[2], b[3], b[4], b[5], b[6], b[7]], [b[8], b[9], b[10], b[11]])
+}
+
+const fn duration_from_parts(secs: u64, nanos: u32) -> Duration {
+    if nanos >= NANOS_PER_SEC {
+        return Duration::NONE;
+    }
+    Duration(Some(time::Duration::new(secs, nanos)))
+}
 
 /// A `Duration` type to represent a span of time, typically used for system
 /// timeouts.
@@ -23,6 +71,13 @@ const NANOS_PER_SEC: u32 = 1_000_000_000;
 /// `Duration`s implement many common traits, including [`Add`], [`Sub`], and other
 /// [`ops`] traits.
 ///
+/// `Duration`s can also be compared with `u64`, which is interpreted as a number
+/// of **nanoseconds**.
+///
+/// `Duration` is a wrapper for [`core::time::Duration`], which
+/// [`std::time::Duration`] re-exports, so it and all of its conversions to and
+/// from the standard library type are available without the `std` feature.
+///
 /// # Examples
 ///
 /// ```
@@ -63,6 +118,20 @@ impl Duration {
     /// ```
     pub const ZERO: Self = Self::from_nanos(0);
 
+    /// The minimum duration.
+    ///
+    /// Durations are unsigned, so this is equal to [`ZERO`](Self::ZERO). This
+    /// is provided for generic code that expects a `MIN`/`MAX` pair.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easytime::Duration;
+    ///
+    /// assert_eq!(Duration::MIN, Duration::ZERO);
+    /// ```
+    pub const MIN: Self = Self::ZERO;
+
     /// The maximum duration.
     ///
     /// May vary by platform as necessary. Must be able to contain the difference between
@@ -79,6 +148,19 @@ impl Duration {
     /// ```
     pub const MAX: Self = Self(Some(time::Duration::MAX));
 
+    /// A tolerance of one microsecond, intended for comparing measured times
+    /// in tests with [`within`](Self::within) or
+    /// [`assert_duration_almost_eq!`](crate::assert_duration_almost_eq).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easytime::Duration;
+    ///
+    /// assert_eq!(Duration::DEFAULT_TEST_TOLERANCE, Duration::from_micros(1));
+    /// ```
+    pub const DEFAULT_TEST_TOLERANCE: Self = Self::from_micros(1);
+
     /// Creates a new `Duration` from the specified number of whole seconds and
     /// additional nanoseconds.
     ///
@@ -100,6 +182,60 @@ impl Duration {
         Self(secs.checked_add(nanos))
     }
 
+    /// Creates a new `Duration` from the specified number of whole seconds and
+    /// additional nanoseconds, returning an error instead of `None`.
+    ///
+    /// Like [`new`](Self::new), nanoseconds greater than 1 billion carry over
+    /// into the seconds. If that overflows, the error's [`kind`] is
+    /// [`TryFromTimeErrorKind::Overflow`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easytime::{Duration, TryFromTimeErrorKind};
+    ///
+    /// assert_eq!(Duration::new_checked(5, 1_500_000_000), Ok(Duration::new(6, 500_000_000)));
+    /// let err = Duration::new_checked(u64::MAX, 1_000_000_000).unwrap_err();
+    /// assert_eq!(err.kind(), TryFromTimeErrorKind::Overflow);
+    /// ```
+    ///
+    /// [`kind`]: TryFromTimeError::kind
+    #[inline]
+    pub const fn new_checked(secs: u64, nanos: u32) -> Result<Self, TryFromTimeError> {
+        match Self::new(secs, nanos) {
+            Self(None) => Err(TryFromTimeError(TryFromTimeErrorKind::Overflow)),
+            dur => Ok(dur),
+        }
+    }
+
+    /// Creates a new `Duration` from the specified number of whole seconds as
+    /// `u128` and additional nanoseconds.
+    ///
+    /// This is useful for interoperating with storage formats that represent
+    /// durations with 128-bit integers. Like [`new`](Self::new), nanoseconds
+    /// greater than 1 billion carry over into the seconds.
+    ///
+    /// Returns [`Duration::NONE`] if the seconds (after the carry) do not fit
+    /// in `u64`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easytime::Duration;
+    ///
+    /// assert_eq!(Duration::from_parts_u128(5, 1_500_000_000), Duration::new(6, 500_000_000));
+    /// assert_eq!(Duration::from_parts_u128(u64::MAX as u128, 999_999_999), Duration::MAX);
+    /// assert!(Duration::from_parts_u128(u64::MAX as u128 + 1, 0).is_none());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn from_parts_u128(secs: u128, nanos: u32) -> Self {
+        if secs > u64::MAX as u128 {
+            return Self::NONE;
+        }
+        Self::new(secs as u64, nanos)
+    }
+
     /// Creates a new `Duration` from the specified number of whole seconds.
     ///
     /// # Examples
@@ -172,6 +308,133 @@ impl Duration {
         Self(Some(time::Duration::from_nanos(nanos)))
     }
 
+    /// Creates a new `Duration` from the specified number of whole seconds.
+    ///
+    /// Returns [`Duration::NONE`] if the result is larger than [`Duration::MAX`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easytime::Duration;
+    ///
+    /// assert_eq!(Duration::from_secs_u128(5), Duration::from_secs(5));
+    /// assert!(Duration::from_secs_u128(u128::MAX).is_none());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn from_secs_u128(secs: u128) -> Self {
+        if secs > u64::MAX as u128 {
+            return Self::NONE;
+        }
+        Self::from_secs(secs as u64)
+    }
+
+    /// Creates a new `Duration` from the specified number of milliseconds.
+    ///
+    /// Returns [`Duration::NONE`] if the result is larger than [`Duration::MAX`].
+    ///
+    /// This is the inverse of [`as_millis`](Self::as_millis).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easytime::Duration;
+    ///
+    /// let duration = Duration::from_secs(5);
+    /// assert_eq!(Duration::from_millis_u128(duration.as_millis().unwrap()), duration);
+    /// assert!(Duration::from_millis_u128(u128::MAX).is_none());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn from_millis_u128(millis: u128) -> Self {
+        let secs = millis / 1_000;
+        if secs > u64::MAX as u128 {
+            return Self::NONE;
+        }
+        Self::new(secs as u64, (millis % 1_000) as u32 * 1_000_000)
+    }
+
+    /// Creates a new `Duration` from the specified number of nanoseconds.
+    ///
+    /// Returns [`Duration::NONE`] if the result is larger than [`Duration::MAX`].
+    ///
+    /// This is the inverse of [`as_nanos`](Self::as_nanos).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easytime::Duration;
+    ///
+    /// assert_eq!(Duration::from_nanos_u128(Duration::MAX.as_nanos().unwrap()), Duration::MAX);
+    /// assert!(Duration::from_nanos_u128(u128::MAX).is_none());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn from_nanos_u128(nanos: u128) -> Self {
+        Self(duration_from_nanos_u128(nanos))
+    }
+
+    /// Creates a new `Duration` from the specified number of whole seconds as
+    /// `u32`.
+    ///
+    /// This never overflows, so the result is never `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easytime::Duration;
+    ///
+    /// const TIMEOUT: Duration = Duration::from_secs_u32(30);
+    /// assert_eq!(TIMEOUT, Duration::from_secs(30));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn from_secs_u32(secs: u32) -> Self {
+        Self::from_secs(secs as u64)
+    }
+
+    /// Creates a new `Duration` from the specified number of milliseconds as
+    /// `u32`.
+    ///
+    /// This never overflows, so the result is never `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easytime::Duration;
+    ///
+    /// const TIMEOUT: Duration = Duration::from_millis_u32(2_569);
+    /// assert_eq!(TIMEOUT, Duration::from_millis(2_569));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn from_millis_u32(millis: u32) -> Self {
+        Self::from_millis(millis as u64)
+    }
+
+    /// Lazily converts each number of nanoseconds in the slice into a `Duration`.
+    ///
+    /// Each entry is converted as in [`from_nanos_u128`](Self::from_nanos_u128),
+    /// so entries larger than [`Duration::MAX`] yield [`Duration::NONE`] without
+    /// affecting the other entries. This does not allocate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easytime::Duration;
+    ///
+    /// let nanos = [1_000_000_123, u128::MAX];
+    /// let mut iter = Duration::from_nanos_slice(&nanos);
+    /// assert_eq!(iter.next(), Some(Duration::new(1, 123)));
+    /// assert_eq!(iter.next(), Some(Duration::NONE));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn from_nanos_slice(nanos: &[u128]) -> impl ExactSizeIterator<Item = Duration> + '_ {
+        nanos.iter().map(|&nanos| Self::from_nanos_u128(nanos))
+    }
+
     /// Returns `true` if this `Duration` spans no time.
     ///
     /// # Examples
@@ -194,6 +457,26 @@ impl Duration {
         matches!((self.as_secs(), self.subsec_nanos()), (Some(0), Some(0)))
     }
 
+    /// Returns `true` if this `Duration` is equal to [`Duration::MAX`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easytime::Duration;
+    ///
+    /// assert!(Duration::MAX.is_max());
+    /// assert!(!(Duration::MAX - Duration::new(0, 1)).is_max());
+    /// assert!(!Duration::NONE.is_max());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn is_max(&self) -> bool {
+        matches!(
+            (self.as_secs(), self.subsec_nanos()),
+            (Some(u64::MAX), Some(nanos)) if nanos == NANOS_PER_SEC - 1
+        )
+    }
+
     /// Returns the number of _whole_ seconds contained by this `Duration`.
     ///
     /// The returned value does not include the fractional (nanosecond) part of the
@@ -347,238 +630,1700 @@ impl Duration {
         }
     }
 
-    // TODO: duration_abs_diff https://github.com/rust-lang/rust/issues/117618 / stabilized in 1.81 https://github.com/rust-lang/rust/pull/127128
-    // /// Computes the absolute difference between `self` and `other`.
-    // ///
-    // /// # Examples
-    // ///
-    // /// ```
-    // /// use easytime::Duration;
-    // ///
-    // /// assert_eq!(Duration::new(100, 0).abs_diff(Duration::new(80, 0)), Duration::new(20, 0));
-    // /// assert_eq!(
-    // ///     Duration::new(100, 400_000_000).abs_diff(Duration::new(110, 0)),
-    // ///     Duration::new(9, 600_000_000)
-    // /// );
-    // /// ```
-    // #[inline]
-    // #[must_use]
-    // pub const fn abs_diff(self, other: Duration) -> Duration {
-    //     if let Some(res) = self.checked_sub(other) {
-    //         res
-    //     } else {
-    //         other.checked_sub(self).unwrap()
-    //     }
-    // }
-
-    // TODO: duration_consts_float stabilized in 1.83 https://github.com/rust-lang/rust/pull/131289
-    /// Returns the number of seconds contained by this `Duration` as `f64`.
+    /// Returns the number of whole seconds and the fractional part in
+    /// nanoseconds contained by this `Duration`.
     ///
-    /// The returned value does include the fractional (nanosecond) part of the duration.
+    /// This is the inverse of [`Duration::new`] and `Duration::from((secs, nanos))`,
+    /// and is useful for FFI boundaries that pass time as a pair of integers.
     ///
     /// # Examples
     ///
     /// ```
     /// use easytime::Duration;
     ///
-    /// let dur = Duration::new(2, 700_000_000);
-    /// assert_eq!(dur.as_secs_f64(), Some(2.7));
+    /// assert_eq!(Duration::new(5, 730_023_852).to_parts(), Some((5, 730_023_852)));
+    /// assert_eq!(Duration::from((5, 1_500_000_000)).to_parts(), Some((6, 500_000_000)));
+    /// assert_eq!(Duration::NONE.to_parts(), None);
     /// ```
     #[inline]
     #[must_use]
-    pub fn as_secs_f64(&self) -> Option<f64> {
-        self.0.as_ref().map(time::Duration::as_secs_f64)
+    pub const fn to_parts(self) -> Option<(u64, u32)> {
+        match &self.0 {
+            Some(d) => Some((d.as_secs(), d.subsec_nanos())),
+            None => None,
+        }
     }
 
-    // TODO: duration_consts_float stabilized in 1.83 https://github.com/rust-lang/rust/pull/131289
-    /// Returns the number of seconds contained by this `Duration` as `f32`.
+    /// Returns a `Duration` with the whole seconds replaced by `secs`, keeping
+    /// the fractional part of this `Duration`.
     ///
-    /// The returned value does include the fractional (nanosecond) part of the duration.
+    /// Returns [`Duration::NONE`] if this `Duration` is `None`.
     ///
     /// # Examples
     ///
     /// ```
     /// use easytime::Duration;
     ///
-    /// let dur = Duration::new(2, 700_000_000);
-    /// assert_eq!(dur.as_secs_f32(), Some(2.7));
+    /// assert_eq!(Duration::new(5, 250).with_secs(7), Duration::new(7, 250));
+    /// assert!(Duration::NONE.with_secs(7).is_none());
     /// ```
     #[inline]
     #[must_use]
-    pub fn as_secs_f32(&self) -> Option<f32> {
-        self.0.as_ref().map(time::Duration::as_secs_f32)
+    pub const fn with_secs(self, secs: u64) -> Self {
+        match &self.0 {
+            Some(d) => Self::new(secs, d.subsec_nanos()),
+            None => Self::NONE,
+        }
     }
 
-    /// Creates a new `Duration` from the specified number of seconds represented
-    /// as `f64`.
+    /// Returns a `Duration` with the fractional part replaced by `nanos`
+    /// nanoseconds, keeping the whole seconds of this `Duration`.
+    ///
+    /// Like [`new`](Self::new), nanoseconds greater than 1 billion carry over
+    /// into the seconds.
+    ///
+    /// Returns [`Duration::NONE`] if this `Duration` is `None` or the result
+    /// overflows.
     ///
     /// # Examples
     ///
     /// ```
     /// use easytime::Duration;
     ///
-    /// let dur = Duration::from_secs_f64(2.7);
-    /// assert_eq!(dur, Duration::new(2, 700_000_000));
+    /// assert_eq!(Duration::new(5, 250).with_nanos(500), Duration::new(5, 500));
+    /// assert_eq!(Duration::new(5, 250).with_nanos(1_000_000_500), Duration::new(6, 500));
+    /// assert!(Duration::MAX.with_nanos(1_000_000_000).is_none());
     /// ```
     #[inline]
     #[must_use]
-    pub fn from_secs_f64(secs: f64) -> Self {
-        // TODO: update implementation based on https://github.com/rust-lang/rust/commit/e0bcf771d6e670988a3d4fdc785ecd5857916f10
-        const MAX_NANOS_F64: f64 = ((u64::MAX as u128 + 1) * (NANOS_PER_SEC as u128)) as f64;
-        let nanos = secs * (NANOS_PER_SEC as f64);
-        if !nanos.is_finite() || nanos >= MAX_NANOS_F64 || nanos < 0. {
-            return Self(None);
+    pub const fn with_nanos(self, nanos: u32) -> Self {
+        match &self.0 {
+            Some(d) => Self::new(d.as_secs(), nanos),
+            None => Self::NONE,
         }
-        let nanos = nanos as u128;
-        Self::new(
-            (nanos / (NANOS_PER_SEC as u128)) as u64,
-            (nanos % (NANOS_PER_SEC as u128)) as u32,
-        )
     }
 
-    /// Creates a new `Duration` from the specified number of seconds represented
-    /// as `f32`.
+    /// Splits this `Duration` into its whole seconds and its fractional part,
+    /// both as `Duration`s.
+    ///
+    /// Returns `(Duration::NONE, Duration::NONE)` if this `Duration` is `None`.
     ///
     /// # Examples
     ///
     /// ```
     /// use easytime::Duration;
     ///
-    /// let dur = Duration::from_secs_f32(2.7);
-    /// assert_eq!(dur, Duration::new(2, 700_000_000));
+    /// let (secs, frac) = Duration::new(5, 730_023_852).split_secs();
+    /// assert_eq!(secs, Duration::from_secs(5));
+    /// assert_eq!(frac, Duration::new(0, 730_023_852));
     /// ```
     #[inline]
     #[must_use]
-    pub fn from_secs_f32(secs: f32) -> Duration {
-        // TODO: update implementation based on https://github.com/rust-lang/rust/commit/e0bcf771d6e670988a3d4fdc785ecd5857916f10
-        const MAX_NANOS_F32: f32 = ((u64::MAX as u128 + 1) * (NANOS_PER_SEC as u128)) as f32;
-        let nanos = secs * (NANOS_PER_SEC as f32);
-        if !nanos.is_finite() || nanos >= MAX_NANOS_F32 || nanos < 0. {
-            return Self(None);
+    pub const fn split_secs(self) -> (Duration, Duration) {
+        match &self.0 {
+            Some(d) => (Self::from_secs(d.as_secs()), Self::from_nanos(d.subsec_nanos() as u64)),
+            None => (Self::NONE, Self::NONE),
         }
-        let nanos = nanos as u128;
-        Self::new(
-            (nanos / (NANOS_PER_SEC as u128)) as u64,
-            (nanos % (NANOS_PER_SEC as u128)) as u32,
-        )
     }
 
-    /// Multiplies `Duration` by `f64`.
+    /// Returns the memory representation of this `Duration` as a byte array in
+    /// little-endian byte order.
+    ///
+    /// The layout is the whole seconds as `u64`, followed by the fractional
+    /// part in nanoseconds as `u32`, i.e., 12 bytes in total.
+    ///
+    /// `None` is encoded as all bytes set to `0xff`. This is never a valid
+    /// `Some` value, because the nanoseconds part is always less than one
+    /// billion.
     ///
     /// # Examples
     ///
     /// ```
     /// use easytime::Duration;
     ///
-    /// let dur = Duration::new(2, 700_000_000);
-    /// assert_eq!(dur.mul_f64(3.14), Duration::new(8, 478_000_000));
-    /// assert_eq!(dur.mul_f64(3.14e5), Duration::new(847_800, 0));
+    /// let bytes = Duration::new(1, 2).to_le_bytes();
+    /// assert_eq!(bytes, [1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0]);
+    /// assert_eq!(Duration::NONE.to_le_bytes(), [0xff; 12]);
     /// ```
     #[inline]
     #[must_use]
-    pub fn mul_f64(self, rhs: f64) -> Duration {
-        self.as_secs_f64().map_or(Self::NONE, |secs| Duration::from_secs_f64(rhs * secs))
+    pub const fn to_le_bytes(self) -> [u8; 12] {
+        let (secs, nanos) = match self.to_parts() {
+            Some(parts) => parts,
+            None => (u64::MAX, u32::MAX),
+        };
+        concat_bytes(secs.to_le_bytes(), nanos.to_le_bytes())
     }
 
-    /// Multiplies `Duration` by `f32`.
+    /// Returns the memory representation of this `Duration` as a byte array in
+    /// big-endian (network) byte order.
+    ///
+    /// See [`to_le_bytes`](Self::to_le_bytes) for the layout.
     ///
     /// # Examples
     ///
     /// ```
     /// use easytime::Duration;
     ///
-    /// let dur = Duration::new(2, 700_000_000);
-    /// // note that due to rounding errors result is slightly different
-    /// // from 8.478 and 847800.0
-    /// assert_eq!(dur.mul_f32(3.14), Duration::new(8, 478_000_640));
-    /// assert_eq!(dur.mul_f32(3.14e5), Duration::new(847799, 969_120_256));
+    /// let bytes = Duration::new(1, 2).to_be_bytes();
+    /// assert_eq!(bytes, [0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 2]);
+    /// assert_eq!(Duration::NONE.to_be_bytes(), [0xff; 12]);
     /// ```
     #[inline]
     #[must_use]
-    pub fn mul_f32(self, rhs: f32) -> Duration {
-        self.as_secs_f32().map_or(Self::NONE, |secs| Duration::from_secs_f32(rhs * secs))
+    pub const fn to_be_bytes(self) -> [u8; 12] {
+        let (secs, nanos) = match self.to_parts() {
+            Some(parts) => parts,
+            None => (u64::MAX, u32::MAX),
+        };
+        concat_bytes(secs.to_be_bytes(), nanos.to_be_bytes())
     }
 
-    /// Divide `Duration` by `f64`.
+    /// Creates a `Duration` from its representation as a byte array in
+    /// little-endian byte order.
+    ///
+    /// See [`to_le_bytes`](Self::to_le_bytes) for the layout. Returns
+    /// [`Duration::NONE`] if the nanoseconds part is not less than one billion,
+    /// which includes the all-`0xff` encoding of `None`.
     ///
     /// # Examples
     ///
     /// ```
     /// use easytime::Duration;
     ///
-    /// let dur = Duration::new(2, 700_000_000);
-    /// assert_eq!(dur.div_f64(3.14), Duration::new(0, 859_872_611));
-    /// // note that truncation is used, not rounding
-    /// assert_eq!(dur.div_f64(3.14e5), Duration::new(0, 8_598));
+    /// let bytes = [1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0];
+    /// assert_eq!(Duration::from_le_bytes(bytes), Duration::new(1, 2));
+    /// assert!(Duration::from_le_bytes([0xff; 12]).is_none());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn from_le_bytes(bytes: [u8; 12]) -> Self {
+        let (secs, nanos) = split_bytes(bytes);
+        duration_from_parts(u64::from_le_bytes(secs), u32::from_le_bytes(nanos))
+    }
+
+    /// Creates a `Duration` from its representation as a byte array in
+    /// big-endian (network) byte order.
+    ///
+    /// See [`to_le_bytes`](Self::to_le_bytes) for the layout. Returns
+    /// [`Duration::NONE`] if the nanoseconds part is not less than one billion,
+    /// which includes the all-`0xff` encoding of `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easytime::Duration;
+    ///
+    /// let bytes = [0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 2];
+    /// assert_eq!(Duration::from_be_bytes(bytes), Duration::new(1, 2));
+    /// assert!(Duration::from_be_bytes([0xff; 12]).is_none());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn from_be_bytes(bytes: [u8; 12]) -> Self {
+        let (secs, nanos) = split_bytes(bytes);
+        duration_from_parts(u64::from_be_bytes(secs), u32::from_be_bytes(nanos))
+    }
+
+    /// Returns the number of _whole_ seconds contained by this `Duration`, or
+    /// `0` if this `Duration` is `None`.
+    ///
+    /// Note that `0` is also returned for a zero duration, so use
+    /// [`as_secs`](Self::as_secs) if `None` must be distinguished.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easytime::Duration;
+    ///
+    /// assert_eq!(Duration::new(5, 730_023_852).as_secs_u64_saturating(), 5);
+    /// assert_eq!(Duration::NONE.as_secs_u64_saturating(), 0);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn as_secs_u64_saturating(&self) -> u64 {
+        match self.as_secs() {
+            Some(secs) => secs,
+            None => 0,
+        }
+    }
+
+    /// Returns the total number of whole milliseconds contained by this
+    /// `Duration` as `u64`, clamped to `u64::MAX`, or `0` if this `Duration`
+    /// is `None`.
+    ///
+    /// This is useful for APIs that take a timeout in milliseconds as `u64`.
+    /// Note that `0` is also returned for a duration shorter than one
+    /// millisecond, so use [`as_millis`](Self::as_millis) if `None` must be
+    /// distinguished.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easytime::Duration;
+    ///
+    /// assert_eq!(Duration::new(5, 730_023_852).as_millis_u64_saturating(), 5_730);
+    /// assert_eq!(Duration::MAX.as_millis_u64_saturating(), u64::MAX);
+    /// assert_eq!(Duration::NONE.as_millis_u64_saturating(), 0);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn as_millis_u64_saturating(&self) -> u64 {
+        match self.as_millis() {
+            Some(millis) if millis > u64::MAX as u128 => u64::MAX,
+            Some(millis) => millis as u64,
+            None => 0,
+        }
+    }
+
+    /// Returns the total number of whole milliseconds contained by this
+    /// `Duration` as `u64`, returning an error instead of clamping.
+    ///
+    /// The error's [`kind`] is:
+    ///
+    /// - [`TryFromTimeErrorKind::Overflow`] if the number of milliseconds does
+    ///   not fit in `u64`.
+    /// - [`TryFromTimeErrorKind::OutOfRange`] if this `Duration` is `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easytime::{Duration, TryFromTimeErrorKind};
+    ///
+    /// assert_eq!(Duration::new(1, 500_999_999).try_as_millis_u64(), Ok(1_500));
+    /// let err = Duration::MAX.try_as_millis_u64().unwrap_err();
+    /// assert_eq!(err.kind(), TryFromTimeErrorKind::Overflow);
+    /// ```
+    ///
+    /// [`kind`]: TryFromTimeError::kind
+    #[inline]
+    #[allow(clippy::cast_possible_truncation)] // `millis` is checked to fit in `u64`.
+    pub const fn try_as_millis_u64(&self) -> Result<u64, TryFromTimeError> {
+        match self.as_millis() {
+            Some(millis) if millis > u64::MAX as u128 => {
+                Err(TryFromTimeError(TryFromTimeErrorKind::Overflow))
+            }
+            Some(millis) => Ok(millis as u64),
+            None => Err(TryFromTimeError(TryFromTimeErrorKind::OutOfRange)),
+        }
+    }
+
+    /// Returns the total number of whole milliseconds contained by this `Duration`,
+    /// or `0` if this `Duration` is `None`.
+    ///
+    /// This is equivalent to `dur.as_millis().unwrap_or(0)`. Note that `None`
+    /// is indistinguishable from [`Duration::ZERO`] in the result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easytime::Duration;
+    ///
+    /// assert_eq!(Duration::new(5, 730_023_852).as_millis_or_zero(), 5_730);
+    /// assert_eq!(Duration::NONE.as_millis_or_zero(), 0);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn as_millis_or_zero(&self) -> u128 {
+        match self.as_millis() {
+            Some(millis) => millis,
+            None => 0,
+        }
+    }
+
+    /// Returns the total number of whole microseconds contained by this `Duration`,
+    /// or `0` if this `Duration` is `None`.
+    ///
+    /// This is equivalent to `dur.as_micros().unwrap_or(0)`. Note that `None`
+    /// is indistinguishable from [`Duration::ZERO`] in the result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easytime::Duration;
+    ///
+    /// assert_eq!(Duration::new(5, 730_023_852).as_micros_or_zero(), 5_730_023);
+    /// assert_eq!(Duration::NONE.as_micros_or_zero(), 0);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn as_micros_or_zero(&self) -> u128 {
+        match self.as_micros() {
+            Some(micros) => micros,
+            None => 0,
+        }
+    }
+
+    /// Returns the total number of whole nanoseconds contained by this `Duration`,
+    /// or `0` if this `Duration` is `None`.
+    ///
+    /// This is equivalent to `dur.as_nanos().unwrap_or(0)`. Note that `None`
+    /// is indistinguishable from [`Duration::ZERO`] in the result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easytime::Duration;
+    ///
+    /// assert_eq!(Duration::new(5, 730_023_852).as_nanos_or_zero(), 5_730_023_852);
+    /// assert_eq!(Duration::NONE.as_nanos_or_zero(), 0);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn as_nanos_or_zero(&self) -> u128 {
+        match self.as_nanos() {
+            Some(nanos) => nanos,
+            None => 0,
+        }
+    }
+
+    /// Returns a compact human-readable representation of this `Duration`,
+    /// such as `"1h 30m 5s"`, or `None` if this `Duration` is `None`.
+    ///
+    /// The output uses the units `d`, `h`, `m`, `s`, `ms`, `us`, and `ns`,
+    /// omits units whose value is zero, and can be parsed back by
+    /// [`humantime::parse_duration`]. Unlike [`humantime::format_duration`],
+    /// which also uses months and years, days is the largest unit, so the
+    /// number of days is not bounded. A zero duration is represented as `"0s"`.
+    ///
+    /// Since nanoseconds is the smallest unit `Duration` can represent, the
+    /// output is always exact and no rounding is performed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easytime::Duration;
+    ///
+    /// assert_eq!(Duration::new(5_405, 0).to_human_string().as_deref(), Some("1h 30m 5s"));
+    /// assert_eq!(Duration::from_micros(1_500).to_human_string().as_deref(), Some("1ms 500us"));
+    /// assert_eq!(Duration::ZERO.to_human_string().as_deref(), Some("0s"));
+    /// assert_eq!(Duration::NONE.to_human_string(), None);
+    /// ```
+    ///
+    /// [`humantime::parse_duration`]: https://docs.rs/humantime/latest/humantime/fn.parse_duration.html
+    /// [`humantime::format_duration`]: https://docs.rs/humantime/latest/humantime/fn.format_duration.html
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    #[must_use]
+    pub fn to_human_string(self) -> Option<alloc::string::String> {
+        use core::fmt::Write as _;
+
+        let d = self.0?;
+        if d.is_zero() {
+            return Some("0s".into());
+        }
+        let secs = d.as_secs();
+        let nanos = d.subsec_nanos();
+        let parts = [
+            (secs / 86_400, "d"),
+            (secs / 3_600 % 24, "h"),
+            (secs / 60 % 60, "m"),
+            (secs % 60, "s"),
+            (u64::from(nanos / 1_000_000), "ms"),
+            (u64::from(nanos / 1_000 % 1_000), "us"),
+            (u64::from(nanos % 1_000), "ns"),
+        ];
+        let mut s = alloc::string::String::new();
+        for (n, unit) in parts {
+            if n == 0 {
+                continue;
+            }
+            if !s.is_empty() {
+                s.push(' ');
+            }
+            let _ = write!(s, "{n}{unit}");
+        }
+        Some(s)
+    }
+
+    /// Formats this `Duration` as a decimal number of `unit` with exactly
+    /// `precision` fractional digits, such as `"1500.00ms"`, or returns `None`
+    /// if this `Duration` is `None`.
+    ///
+    /// Unlike the `Debug` implementation, which chooses the unit based on the
+    /// magnitude, this always uses `unit`, which is useful for tabular output.
+    /// The value is rounded half up to `precision` digits; it is computed from
+    /// the exact number of nanoseconds, so no floating-point error occurs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easytime::{Duration, DurationUnit};
+    ///
+    /// let dur = Duration::from_micros(1_500);
+    /// assert_eq!(dur.format_as(DurationUnit::Millis, 2).as_deref(), Some("1.50ms"));
+    /// assert_eq!(dur.format_as(DurationUnit::Micros, 0).as_deref(), Some("1500µs"));
+    /// assert_eq!(dur.format_as(DurationUnit::Secs, 2).as_deref(), Some("0.00s"));
+    /// assert_eq!(Duration::NONE.format_as(DurationUnit::Secs, 2), None);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    #[must_use]
+    pub fn format_as(&self, unit: DurationUnit, precision: usize) -> Option<alloc::string::String> {
+        use core::fmt::Write as _;
+
+        let nanos = self.as_nanos()?;
+        let (unit_digits, suffix) = match unit {
+            DurationUnit::Nanos => (0, "ns"),
+            DurationUnit::Micros => (3, "µs"),
+            DurationUnit::Millis => (6, "ms"),
+            DurationUnit::Secs => (9, "s"),
+        };
+        // Digits beyond nanosecond resolution are always zero.
+        let exact_digits = precision.min(unit_digits);
+        let rounding = 10_u128.pow((unit_digits - exact_digits) as u32);
+        // `nanos` is less than 2^94, so this cannot overflow.
+        let scaled = (nanos + rounding / 2) / rounding;
+        let divisor = 10_u128.pow(exact_digits as u32);
+        let mut s = alloc::string::String::new();
+        let _ = write!(s, "{}", scaled / divisor);
+        if precision > 0 {
+            s.push('.');
+            if exact_digits > 0 {
+                let _ = write!(s, "{:0width$}", scaled % divisor, width = exact_digits);
+            }
+            s.extend(core::iter::repeat('0').take(precision - exact_digits));
+        }
+        s.push_str(suffix);
+        Some(s)
+    }
+
+    /// Returns an object that formats this `Duration` without the `Some(...)`
+    /// wrapper printed by its `Debug` implementation.
+    ///
+    /// Both `Debug` and `Display` of the returned object format the contained
+    /// [`std::time::Duration`] with its `Debug` implementation (e.g., `7s`), and
+    /// print `None` if this `Duration` is `None`. Formatting options such as
+    /// precision are respected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easytime::Duration;
+    ///
+    /// let dur = Duration::new(7, 0);
+    /// assert_eq!(format!("{dur:?}"), "Some(7s)");
+    /// assert_eq!(format!("{:?}", dur.display_inner()), "7s");
+    /// assert_eq!(format!("{}", dur.display_inner()), "7s");
+    /// assert_eq!(format!("{}", Duration::NONE.display_inner()), "None");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn display_inner(self) -> DisplayInner {
+        DisplayInner(self)
+    }
+
+    // TODO: duration_abs_diff https://github.com/rust-lang/rust/issues/117618 / stabilized in 1.81 https://github.com/rust-lang/rust/pull/127128
+    // /// Computes the absolute difference between `self` and `other`.
+    // ///
+    // /// # Examples
+    // ///
+    // /// ```
+    // /// use easytime::Duration;
+    // ///
+    // /// assert_eq!(Duration::new(100, 0).abs_diff(Duration::new(80, 0)), Duration::new(20, 0));
+    // /// assert_eq!(
+    // ///     Duration::new(100, 400_000_000).abs_diff(Duration::new(110, 0)),
+    // ///     Duration::new(9, 600_000_000)
+    // /// );
+    // /// ```
+    // #[inline]
+    // #[must_use]
+    // pub const fn abs_diff(self, other: Duration) -> Duration {
+    //     if let Some(res) = self.checked_sub(other) {
+    //         res
+    //     } else {
+    //         other.checked_sub(self).unwrap()
+    //     }
+    // }
+
+    /// Returns `true` if the absolute difference between `self` and `other` is
+    /// less than or equal to `tolerance`.
+    ///
+    /// Returns `false` if any of `self`, `other`, or `tolerance` is `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easytime::Duration;
+    ///
+    /// let tolerance = Duration::from_micros(1);
+    /// assert!(Duration::from_nanos(1_500).approx_eq(Duration::from_nanos(1_000), tolerance));
+    /// assert!(Duration::from_nanos(1_000).approx_eq(Duration::from_nanos(1_500), tolerance));
+    /// assert!(!Duration::from_nanos(2_001).approx_eq(Duration::from_nanos(1_000), tolerance));
+    /// assert!(!Duration::NONE.approx_eq(Duration::NONE, tolerance));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn approx_eq(self, other: Duration, tolerance: Duration) -> bool {
+        match (self.as_nanos(), other.as_nanos(), tolerance.as_nanos()) {
+            (Some(a), Some(b), Some(tolerance)) => {
+                let diff = if a > b { a - b } else { b - a };
+                diff <= tolerance
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns `true` if `other` is within `tolerance` of `self`, inclusive.
+    ///
+    /// This is equivalent to [`approx_eq`](Self::approx_eq), and is typically
+    /// used with [`Duration::DEFAULT_TEST_TOLERANCE`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easytime::Duration;
+    ///
+    /// let expected = Duration::from_millis(5);
+    /// let measured = Duration::from_nanos(5_000_800);
+    /// assert!(measured.within(expected, Duration::DEFAULT_TEST_TOLERANCE));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn within(self, other: Duration, tolerance: Duration) -> bool {
+        self.approx_eq(other, tolerance)
+    }
+
+    // TODO: duration_consts_float stabilized in 1.83 https://github.com/rust-lang/rust/pull/131289
+    /// Returns the number of seconds contained by this `Duration` as `f64`.
+    ///
+    /// The returned value does include the fractional (nanosecond) part of the duration.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easytime::Duration;
+    ///
+    /// let dur = Duration::new(2, 700_000_000);
+    /// assert_eq!(dur.as_secs_f64(), Some(2.7));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn as_secs_f64(&self) -> Option<f64> {
+        self.0.as_ref().map(time::Duration::as_secs_f64)
+    }
+
+    /// Returns the number of seconds contained by this `Duration` as `f64`,
+    /// or an error if the conversion may lose precision.
+    ///
+    /// The conversion is considered exact if the total number of nanoseconds
+    /// is at most 2<sup>53</sup> (about 104 days), i.e., it fits in the
+    /// mantissa of `f64`. In that case the result is the `f64` closest to
+    /// the exact number of seconds.
+    ///
+    /// The error's [`kind`] is [`TryFromTimeErrorKind::PrecisionLoss`] if the
+    /// number of nanoseconds is larger than 2<sup>53</sup>, and
+    /// [`TryFromTimeErrorKind::Unknown`] if this `Duration` is `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easytime::{Duration, TryFromTimeErrorKind};
+    ///
+    /// assert_eq!(Duration::new(2, 700_000_000).as_secs_f64_exact(), Ok(2.7));
+    /// let err = Duration::from_secs(200 * 24 * 60 * 60).as_secs_f64_exact().unwrap_err();
+    /// assert_eq!(err.kind(), TryFromTimeErrorKind::PrecisionLoss);
+    /// ```
+    ///
+    /// [`kind`]: TryFromTimeError::kind
+    #[inline]
+    pub fn as_secs_f64_exact(&self) -> Result<f64, TryFromTimeError> {
+        const MAX_EXACT_NANOS: u128 = 1 << f64::MANTISSA_DIGITS;
+        match self.as_nanos() {
+            Some(nanos) if nanos <= MAX_EXACT_NANOS => Ok(nanos as f64 / NANOS_PER_SEC as f64),
+            Some(_) => Err(TryFromTimeError(TryFromTimeErrorKind::PrecisionLoss)),
+            None => Err(TryFromTimeError::new()),
+        }
+    }
+
+    /// Compares this `Duration` with the specified number of seconds
+    /// represented as `f64`.
+    ///
+    /// This compares the result of [`as_secs_f64`](Self::as_secs_f64) with
+    /// `secs`, so it is subject to the precision of `f64`.
+    ///
+    /// Returns `None` if this `Duration` is `None` or `secs` is not finite.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cmp::Ordering;
+    ///
+    /// use easytime::Duration;
+    ///
+    /// let dur = Duration::new(2, 700_000_000);
+    /// assert_eq!(dur.cmp_secs_f64(2.5), Some(Ordering::Greater));
+    /// assert_eq!(dur.cmp_secs_f64(2.7), Some(Ordering::Equal));
+    /// assert_eq!(dur.cmp_secs_f64(f64::NAN), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn cmp_secs_f64(&self, secs: f64) -> Option<cmp::Ordering> {
+        if !secs.is_finite() {
+            return None;
+        }
+        self.as_secs_f64()?.partial_cmp(&secs)
+    }
+
+    /// Compares this `Duration` with `other` by their number of seconds
+    /// represented as `f64`.
+    ///
+    /// This compares the results of [`as_secs_f64`](Self::as_secs_f64), so it
+    /// is subject to the precision of `f64`: durations longer than about 104
+    /// days may lose nanosecond precision, so different durations can compare
+    /// as equal. Use the [`Ord`] implementation to compare two durations
+    /// exactly.
+    ///
+    /// Returns `None` if either `Duration` is `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cmp::Ordering;
+    ///
+    /// use easytime::Duration;
+    ///
+    /// let a = Duration::from_secs(1 << 40);
+    /// let b = a + Duration::from_nanos(1);
+    /// assert_eq!(a.cmp_as_secs_f64(&b), Some(Ordering::Equal));
+    /// assert_eq!(a.cmp(&b), Ordering::Less);
+    /// assert_eq!(a.cmp_as_secs_f64(&Duration::NONE), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn cmp_as_secs_f64(&self, other: &Self) -> Option<cmp::Ordering> {
+        self.as_secs_f64()?.partial_cmp(&other.as_secs_f64()?)
+    }
+
+    // TODO: duration_consts_float stabilized in 1.83 https://github.com/rust-lang/rust/pull/131289
+    /// Returns the number of seconds contained by this `Duration` as `f32`.
+    ///
+    /// The returned value does include the fractional (nanosecond) part of the duration.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easytime::Duration;
+    ///
+    /// let dur = Duration::new(2, 700_000_000);
+    /// assert_eq!(dur.as_secs_f32(), Some(2.7));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn as_secs_f32(&self) -> Option<f32> {
+        self.0.as_ref().map(time::Duration::as_secs_f32)
+    }
+
+    /// Creates a new `Duration` from the specified number of seconds represented
+    /// as `f64`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easytime::Duration;
+    ///
+    /// let dur = Duration::from_secs_f64(2.7);
+    /// assert_eq!(dur, Duration::new(2, 700_000_000));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn from_secs_f64(secs: f64) -> Self {
+        // TODO: update implementation based on https://github.com/rust-lang/rust/commit/e0bcf771d6e670988a3d4fdc785ecd5857916f10
+        const MAX_NANOS_F64: f64 = ((u64::MAX as u128 + 1) * (NANOS_PER_SEC as u128)) as f64;
+        let nanos = secs * (NANOS_PER_SEC as f64);
+        if !nanos.is_finite() || nanos >= MAX_NANOS_F64 || nanos < 0. {
+            return Self(None);
+        }
+        let nanos = nanos as u128;
+        Self::new(
+            (nanos / (NANOS_PER_SEC as u128)) as u64,
+            (nanos % (NANOS_PER_SEC as u128)) as u32,
+        )
+    }
+
+    /// Creates a new `Duration` from the specified number of seconds represented
+    /// as `f64`, rounding the sub-nanosecond remainder according to `mode`.
+    ///
+    /// [`from_secs_f64`](Self::from_secs_f64) is the same as this function with
+    /// [`RoundMode::Trunc`].
+    ///
+    /// Returns [`Duration::NONE`] if `secs` is negative, not finite, or too
+    /// large to be represented (including after rounding up).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easytime::{Duration, RoundMode};
+    ///
+    /// let secs = 2.5e-9;
+    /// assert_eq!(Duration::from_secs_f64_rounded(secs, RoundMode::Trunc), Duration::from_nanos(2));
+    /// assert_eq!(Duration::from_secs_f64_rounded(secs, RoundMode::Up), Duration::from_nanos(3));
+    /// // Ties are rounded to even.
+    /// assert_eq!(Duration::from_secs_f64_rounded(secs, RoundMode::Nearest), Duration::from_nanos(2));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn from_secs_f64_rounded(secs: f64, mode: RoundMode) -> Self {
+        const MAX_NANOS_F64: f64 = ((u64::MAX as u128 + 1) * (NANOS_PER_SEC as u128)) as f64;
+        let nanos = secs * (NANOS_PER_SEC as f64);
+        if !nanos.is_finite() || nanos >= MAX_NANOS_F64 || nanos < 0. {
+            return Self(None);
+        }
+        let int = nanos as u128;
+        // Values of at least 2^53 have no fractional part, so this is exact.
+        let frac = nanos - int as f64;
+        let round_up = match mode {
+            RoundMode::Trunc | RoundMode::Down => false,
+            RoundMode::Up => frac > 0.,
+            RoundMode::Nearest => frac > 0.5 || frac == 0.5 && int % 2 == 1,
+        };
+        Self(duration_from_nanos_u128(if round_up { int + 1 } else { int }))
+    }
+
+    /// Creates a new `Duration` from the specified number of seconds represented
+    /// as `f64`, returning an error instead of `None`.
+    ///
+    /// The error's [`kind`] is:
+    ///
+    /// - [`TryFromTimeErrorKind::Underflow`] if `secs` is negative.
+    /// - [`TryFromTimeErrorKind::Overflow`] if `secs` is too large to be
+    ///   represented (including positive infinity).
+    /// - [`TryFromTimeErrorKind::OutOfRange`] if `secs` is NaN.
+    ///
+    /// That is, this fails for negative, non-finite, or too-large inputs. Like
+    /// [`from_secs_f64`](Self::from_secs_f64), the sub-nanosecond remainder is
+    /// truncated, whereas [`std::time::Duration::try_from_secs_f64`] rounds it
+    /// to the nearest nanosecond (see
+    /// [`from_secs_f64_rounded`](Self::from_secs_f64_rounded)). So the result,
+    /// and whether an input just below the upper bound is accepted, can differ
+    /// from the standard library.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easytime::{Duration, TryFromTimeErrorKind};
+    ///
+    /// assert_eq!(Duration::try_from_secs_f64(2.7), Ok(Duration::from_secs_f64(2.7)));
+    /// let err = Duration::try_from_secs_f64(-1.0).unwrap_err();
+    /// assert_eq!(err.kind(), TryFromTimeErrorKind::Underflow);
+    /// let err = Duration::try_from_secs_f64(f64::INFINITY).unwrap_err();
+    /// assert_eq!(err.kind(), TryFromTimeErrorKind::Overflow);
+    /// let err = Duration::try_from_secs_f64(f64::NAN).unwrap_err();
+    /// assert_eq!(err.kind(), TryFromTimeErrorKind::OutOfRange);
+    /// ```
+    ///
+    /// [`kind`]: TryFromTimeError::kind
+    pub fn try_from_secs_f64(secs: f64) -> Result<Self, TryFromTimeError> {
+        if secs.is_nan() {
+            return Err(TryFromTimeError(TryFromTimeErrorKind::OutOfRange));
+        }
+        if secs < 0. {
+            return Err(TryFromTimeError(TryFromTimeErrorKind::Underflow));
+        }
+        match Self::from_secs_f64(secs) {
+            Self(None) => Err(TryFromTimeError(TryFromTimeErrorKind::Overflow)),
+            dur => Ok(dur),
+        }
+    }
+
+    /// Creates a new `Duration` from the specified number of seconds represented
+    /// as `f32`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easytime::Duration;
+    ///
+    /// let dur = Duration::from_secs_f32(2.7);
+    /// assert_eq!(dur, Duration::new(2, 700_000_000));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn from_secs_f32(secs: f32) -> Duration {
+        // TODO: update implementation based on https://github.com/rust-lang/rust/commit/e0bcf771d6e670988a3d4fdc785ecd5857916f10
+        const MAX_NANOS_F32: f32 = ((u64::MAX as u128 + 1) * (NANOS_PER_SEC as u128)) as f32;
+        let nanos = secs * (NANOS_PER_SEC as f32);
+        if !nanos.is_finite() || nanos >= MAX_NANOS_F32 || nanos < 0. {
+            return Self(None);
+        }
+        let nanos = nanos as u128;
+        Self::new(
+            (nanos / (NANOS_PER_SEC as u128)) as u64,
+            (nanos % (NANOS_PER_SEC as u128)) as u32,
+        )
+    }
+
+    /// Creates a new `Duration` from the specified number of seconds represented
+    /// as `f32`, returning an error instead of `None`.
+    ///
+    /// The error's [`kind`] is:
+    ///
+    /// - [`TryFromTimeErrorKind::Underflow`] if `secs` is negative.
+    /// - [`TryFromTimeErrorKind::Overflow`] if `secs` is too large to be
+    ///   represented (including positive infinity).
+    /// - [`TryFromTimeErrorKind::OutOfRange`] if `secs` is NaN.
+    ///
+    /// That is, this fails for negative, non-finite, or too-large inputs. Like
+    /// [`from_secs_f32`](Self::from_secs_f32), the sub-nanosecond remainder is
+    /// truncated, whereas [`std::time::Duration::try_from_secs_f32`] rounds it
+    /// to the nearest nanosecond. So the result, and whether an input just
+    /// below the upper bound is accepted, can differ from the standard library.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easytime::{Duration, TryFromTimeErrorKind};
+    ///
+    /// assert_eq!(Duration::try_from_secs_f32(2.7), Ok(Duration::from_secs_f32(2.7)));
+    /// let err = Duration::try_from_secs_f32(-1.0).unwrap_err();
+    /// assert_eq!(err.kind(), TryFromTimeErrorKind::Underflow);
+    /// let err = Duration::try_from_secs_f32(f32::INFINITY).unwrap_err();
+    /// assert_eq!(err.kind(), TryFromTimeErrorKind::Overflow);
+    /// let err = Duration::try_from_secs_f32(f32::NAN).unwrap_err();
+    /// assert_eq!(err.kind(), TryFromTimeErrorKind::OutOfRange);
+    /// ```
+    ///
+    /// [`kind`]: TryFromTimeError::kind
+    pub fn try_from_secs_f32(secs: f32) -> Result<Self, TryFromTimeError> {
+        if secs.is_nan() {
+            return Err(TryFromTimeError(TryFromTimeErrorKind::OutOfRange));
+        }
+        if secs < 0. {
+            return Err(TryFromTimeError(TryFromTimeErrorKind::Underflow));
+        }
+        match Self::from_secs_f32(secs) {
+            Self(None) => Err(TryFromTimeError(TryFromTimeErrorKind::Overflow)),
+            dur => Ok(dur),
+        }
+    }
+
+    /// Multiplies `Duration` by `f64`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easytime::Duration;
+    ///
+    /// let dur = Duration::new(2, 700_000_000);
+    /// assert_eq!(dur.mul_f64(3.14), Duration::new(8, 478_000_000));
+    /// assert_eq!(dur.mul_f64(3.14e5), Duration::new(847_800, 0));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn mul_f64(self, rhs: f64) -> Duration {
+        self.as_secs_f64().map_or(Self::NONE, |secs| Duration::from_secs_f64(rhs * secs))
+    }
+
+    /// Multiplies `Duration` by `f32`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easytime::Duration;
+    ///
+    /// let dur = Duration::new(2, 700_000_000);
+    /// // note that due to rounding errors result is slightly different
+    /// // from 8.478 and 847800.0
+    /// assert_eq!(dur.mul_f32(3.14), Duration::new(8, 478_000_640));
+    /// assert_eq!(dur.mul_f32(3.14e5), Duration::new(847799, 969_120_256));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn mul_f32(self, rhs: f32) -> Duration {
+        self.as_secs_f32().map_or(Self::NONE, |secs| Duration::from_secs_f32(rhs * secs))
+    }
+
+    /// Multiplies `Duration` by `f64`, returning an error instead of `None`.
+    ///
+    /// Unlike [`mul_f64`](Self::mul_f64), this lets callers distinguish
+    /// misuse from a valid result. The error's [`kind`] is:
+    ///
+    /// - [`TryFromTimeErrorKind::OutOfRange`] if `rhs` is NaN or infinite.
+    /// - [`TryFromTimeErrorKind::Overflow`] if the result is too large to be
+    ///   represented.
+    /// - [`TryFromTimeErrorKind::Underflow`] if the result is negative.
+    /// - [`TryFromTimeErrorKind::Unknown`] if `self` is `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easytime::{Duration, TryFromTimeErrorKind};
+    ///
+    /// let dur = Duration::new(2, 700_000_000);
+    /// assert_eq!(dur.checked_mul_f64(2.0), Ok(Duration::new(5, 400_000_000)));
+    /// let err = dur.checked_mul_f64(f64::NAN).unwrap_err();
+    /// assert_eq!(err.kind(), TryFromTimeErrorKind::OutOfRange);
+    /// let err = dur.checked_mul_f64(-1.0).unwrap_err();
+    /// assert_eq!(err.kind(), TryFromTimeErrorKind::Underflow);
+    /// ```
+    ///
+    /// [`kind`]: TryFromTimeError::kind
+    #[inline]
+    pub fn checked_mul_f64(self, rhs: f64) -> Result<Duration, TryFromTimeError> {
+        let secs = self.as_secs_f64().ok_or(TryFromTimeError::new())?;
+        if !rhs.is_finite() {
+            return Err(TryFromTimeError(TryFromTimeErrorKind::OutOfRange));
+        }
+        Self::try_from_secs_f64(rhs * secs)
+    }
+
+    /// Multiplies `Duration` by `f32`, returning an error instead of `None`.
+    ///
+    /// Unlike [`mul_f32`](Self::mul_f32), this lets callers distinguish
+    /// misuse from a valid result. The error's [`kind`] is:
+    ///
+    /// - [`TryFromTimeErrorKind::OutOfRange`] if `rhs` is NaN or infinite.
+    /// - [`TryFromTimeErrorKind::Overflow`] if the result is too large to be
+    ///   represented.
+    /// - [`TryFromTimeErrorKind::Underflow`] if the result is negative.
+    /// - [`TryFromTimeErrorKind::Unknown`] if `self` is `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easytime::{Duration, TryFromTimeErrorKind};
+    ///
+    /// let dur = Duration::new(2, 700_000_000);
+    /// assert_eq!(dur.checked_mul_f32(2.0), Ok(Duration::new(5, 400_000_000)));
+    /// let err = dur.checked_mul_f32(f32::NAN).unwrap_err();
+    /// assert_eq!(err.kind(), TryFromTimeErrorKind::OutOfRange);
+    /// let err = dur.checked_mul_f32(-1.0).unwrap_err();
+    /// assert_eq!(err.kind(), TryFromTimeErrorKind::Underflow);
+    /// ```
+    ///
+    /// [`kind`]: TryFromTimeError::kind
+    #[inline]
+    pub fn checked_mul_f32(self, rhs: f32) -> Result<Duration, TryFromTimeError> {
+        let secs = self.as_secs_f32().ok_or(TryFromTimeError::new())?;
+        if !rhs.is_finite() {
+            return Err(TryFromTimeError(TryFromTimeErrorKind::OutOfRange));
+        }
+        Self::try_from_secs_f32(rhs * secs)
+    }
+
+    /// Divide `Duration` by `f64`.
+    ///
+    /// Returns [`Duration::NONE`] if `self` is `None`, `rhs` is zero, negative,
+    /// or not finite, or the result overflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easytime::Duration;
+    ///
+    /// let dur = Duration::new(2, 700_000_000);
+    /// assert_eq!(dur.div_f64(3.14), Duration::new(0, 859_872_611));
+    /// // note that truncation is used, not rounding
+    /// assert_eq!(dur.div_f64(3.14e5), Duration::new(0, 8_598));
+    /// assert!(dur.div_f64(0.0).is_none());
+    /// assert!(dur.div_f64(-1.0).is_none());
+    /// assert!(Duration::ZERO.div_f64(-1.0).is_none());
     /// ```
     #[inline]
     #[must_use]
     pub fn div_f64(self, rhs: f64) -> Duration {
+        // Check the sign explicitly, since zero divided by a negative value
+        // is `-0.0`, which converts to `Duration::ZERO`.
+        if !rhs.is_finite() || rhs.is_sign_negative() {
+            return Self::NONE;
+        }
         self.as_secs_f64().map_or(Self::NONE, |secs| Duration::from_secs_f64(secs / rhs))
     }
 
-    /// Divide `Duration` by `f32`.
+    /// Divides `Duration` by `f64`, returning an error instead of `None`.
+    ///
+    /// Unlike [`div_f64`](Self::div_f64), this lets callers distinguish
+    /// misuse from a valid result. The error's [`kind`] is:
+    ///
+    /// - [`TryFromTimeErrorKind::OutOfRange`] if `rhs` is zero or not finite.
+    /// - [`TryFromTimeErrorKind::Overflow`] if the result is too large to be
+    ///   represented.
+    /// - [`TryFromTimeErrorKind::Underflow`] if the result is negative.
+    /// - [`TryFromTimeErrorKind::Unknown`] if `self` is `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easytime::{Duration, TryFromTimeErrorKind};
+    ///
+    /// let dur = Duration::new(2, 700_000_000);
+    /// assert_eq!(dur.checked_div_f64(3.14), Ok(Duration::new(0, 859_872_611)));
+    /// let err = dur.checked_div_f64(0.0).unwrap_err();
+    /// assert_eq!(err.kind(), TryFromTimeErrorKind::OutOfRange);
+    /// ```
+    ///
+    /// [`kind`]: TryFromTimeError::kind
+    pub fn checked_div_f64(self, rhs: f64) -> Result<Duration, TryFromTimeError> {
+        let secs = self.as_secs_f64().ok_or(TryFromTimeError::new())?;
+        if rhs == 0.0 || !rhs.is_finite() {
+            return Err(TryFromTimeError(TryFromTimeErrorKind::OutOfRange));
+        }
+        let res = secs / rhs;
+        if res < 0.0 {
+            return Err(TryFromTimeError(TryFromTimeErrorKind::Underflow));
+        }
+        match Duration::from_secs_f64(res) {
+            Self(None) => Err(TryFromTimeError(TryFromTimeErrorKind::Overflow)),
+            dur => Ok(dur),
+        }
+    }
+
+    /// Divide `Duration` by `f32`.
+    ///
+    /// Returns [`Duration::NONE`] if `self` is `None`, `rhs` is zero, negative,
+    /// or not finite, or the result overflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easytime::Duration;
+    ///
+    /// let dur = Duration::new(2, 700_000_000);
+    /// // note that due to rounding errors result is slightly
+    /// // different from 0.859_872_611
+    /// assert_eq!(dur.div_f32(3.14), Duration::new(0, 859_872_576));
+    /// // note that truncation is used, not rounding
+    /// assert_eq!(dur.div_f32(3.14e5), Duration::new(0, 8_598));
+    /// assert!(dur.div_f32(0.0).is_none());
+    /// assert!(dur.div_f32(-1.0).is_none());
+    /// assert!(Duration::ZERO.div_f32(-1.0).is_none());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn div_f32(self, rhs: f32) -> Duration {
+        // Check the sign explicitly, since zero divided by a negative value
+        // is `-0.0`, which converts to `Duration::ZERO`.
+        if !rhs.is_finite() || rhs.is_sign_negative() {
+            return Self::NONE;
+        }
+        self.as_secs_f32().map_or(Self::NONE, |secs| Duration::from_secs_f32(secs / rhs))
+    }
+
+    /// Returns the midpoint of `self` and `other`, i.e., their average,
+    /// rounded down to the nearest nanosecond.
+    ///
+    /// Unlike `(self + other) / 2`, this does not overflow even if the sum of
+    /// the durations is larger than [`Duration::MAX`].
+    ///
+    /// Returns [`Duration::NONE`] if either `self` or `other` is `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easytime::Duration;
+    ///
+    /// let a = Duration::from_secs(1);
+    /// let b = Duration::from_secs(4);
+    /// assert_eq!(a.midpoint(b), Duration::new(2, 500_000_000));
+    /// assert_eq!(Duration::MAX.midpoint(Duration::MAX), Duration::MAX);
+    /// assert!(((Duration::MAX + Duration::MAX) / 2).is_none());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn midpoint(self, other: Duration) -> Duration {
+        match (self.as_nanos(), other.as_nanos()) {
+            // Both are less than 2^94, so this cannot overflow.
+            (Some(lhs), Some(rhs)) => Self(duration_from_nanos_u128((lhs + rhs) / 2)),
+            _ => Self::NONE,
+        }
+    }
+
+    /// Linearly interpolates between `self` and `other`.
+    ///
+    /// `t` is clamped to `[0.0, 1.0]`, so `t <= 0.0` returns `self` and
+    /// `t >= 1.0` returns `other`. `other` may be shorter than `self`.
+    /// The result is truncated to the nanosecond.
+    ///
+    /// Returns [`Duration::NONE`] if either `self` or `other` is `None`, or
+    /// `t` is not finite.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easytime::Duration;
+    ///
+    /// let a = Duration::from_secs(1);
+    /// let b = Duration::from_secs(3);
+    /// assert_eq!(a.lerp(b, 0.5), Duration::from_secs(2));
+    /// assert_eq!(b.lerp(a, 0.25), Duration::new(2, 500_000_000));
+    /// assert_eq!(a.lerp(b, 2.0), b);
+    /// assert!(a.lerp(b, f64::NAN).is_none());
+    /// ```
+    #[inline]
+    #[must_use]
+    #[allow(clippy::cast_possible_wrap)] // `as_nanos` is less than 2^94, so it fits in `i128`.
+    pub fn lerp(self, other: Duration, t: f64) -> Duration {
+        let (lhs, rhs) = match (self.as_nanos(), other.as_nanos()) {
+            (Some(lhs), Some(rhs)) if t.is_finite() => (lhs as i128, rhs as i128),
+            _ => return Self::NONE,
+        };
+        if t <= 0. {
+            return self;
+        }
+        if t >= 1. {
+            return other;
+        }
+        // Clamp to the endpoints to absorb rounding errors of `f64`, so the
+        // result is non-negative and not larger than `Duration::MAX`.
+        let nanos = (lhs + ((rhs - lhs) as f64 * t) as i128).clamp(lhs.min(rhs), lhs.max(rhs));
+        Self(duration_from_nanos_u128(nanos as u128))
+    }
+
+    // TODO: div_duration https://github.com/rust-lang/rust/issues/63139 / stabilized in 1.80 https://github.com/rust-lang/rust/pull/124667
+    // TODO: duration_consts_float stabilized in 1.83 https://github.com/rust-lang/rust/pull/131289
+    // /// Divides `Duration` by `Duration` and returns `f64`.
+    // ///
+    // /// # Examples
+    // ///
+    // /// ```
+    // /// use easytime::Duration;
+    // ///
+    // /// let dur1 = Duration::new(2, 700_000_000);
+    // /// let dur2 = Duration::new(5, 400_000_000);
+    // /// assert_eq!(dur1.div_duration_f64(dur2), 0.5);
+    // /// ```
+    // #[inline]
+    // #[must_use]
+    // pub fn div_duration_f64(self, rhs: Duration) -> f64 {
+    //     let self_nanos =
+    //         (self.secs as f64) * (NANOS_PER_SEC as f64) + (self.nanos.as_inner() as f64);
+    //     let rhs_nanos = (rhs.secs as f64) * (NANOS_PER_SEC as f64) + (rhs.nanos.as_inner() as f64);
+    //     self_nanos / rhs_nanos
+    // }
+
+    // TODO: div_duration https://github.com/rust-lang/rust/issues/63139 / stabilized in 1.80 https://github.com/rust-lang/rust/pull/124667
+    // TODO: duration_consts_float stabilized in 1.83 https://github.com/rust-lang/rust/pull/131289
+    // /// Divides `Duration` by `Duration` and returns `f32`.
+    // ///
+    // /// # Examples
+    // ///
+    // /// ```
+    // /// use easytime::Duration;
+    // ///
+    // /// let dur1 = Duration::new(2, 700_000_000);
+    // /// let dur2 = Duration::new(5, 400_000_000);
+    // /// assert_eq!(dur1.div_duration_f32(dur2), 0.5);
+    // /// ```
+    // #[inline]
+    // #[must_use]
+    // pub fn div_duration_f32(self, rhs: Duration) -> f32 {
+    //     let self_nanos =
+    //         (self.secs as f32) * (NANOS_PER_SEC as f32) + (self.nanos.as_inner() as f32);
+    //     let rhs_nanos = (rhs.secs as f32) * (NANOS_PER_SEC as f32) + (rhs.nanos.as_inner() as f32);
+    //     self_nanos / rhs_nanos
+    // }
+
+    /// Divides `Duration` by `Duration` and returns the integer quotient,
+    /// i.e., how many whole `rhs` fit in `self`.
+    ///
+    /// Returns `None` if either operand is `None` or `rhs` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easytime::Duration;
+    ///
+    /// assert_eq!(Duration::from_secs(10).div_duration_u32(Duration::from_secs(3)), Some(3));
+    /// assert_eq!(Duration::from_secs(10).div_duration_u32(Duration::ZERO), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn div_duration_u32(self, rhs: Duration) -> Option<u128> {
+        match (self.as_nanos(), rhs.as_nanos()) {
+            (Some(lhs), Some(rhs)) => lhs.checked_div(rhs),
+            _ => None,
+        }
+    }
+
+    /// Multiplies `Duration` by `2^exp`.
+    ///
+    /// Returns [`Duration::NONE`] if `self` is `None` or the result overflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easytime::Duration;
+    ///
+    /// assert_eq!(Duration::from_millis(100).checked_mul_pow2(3), Duration::from_millis(800));
+    /// assert!(Duration::from_secs(1).checked_mul_pow2(64).is_none());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn checked_mul_pow2(self, exp: u32) -> Duration {
+        match self.as_nanos() {
+            Some(0) => Self::ZERO,
+            Some(nanos) if exp < nanos.leading_zeros() => {
+                Self(duration_from_nanos_u128(nanos << exp))
+            }
+            _ => Self::NONE,
+        }
+    }
+
+    /// Computes an exponential backoff delay: `self * 2^attempt`, capped at `cap`.
+    ///
+    /// `self` is the delay of the first attempt (`attempt == 0`). If the
+    /// multiplication overflows, `cap` is returned.
+    ///
+    /// Returns [`Duration::NONE`] if `self` or `cap` is `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easytime::Duration;
+    ///
+    /// let base = Duration::from_millis(100);
+    /// let cap = Duration::from_secs(1);
+    /// assert_eq!(base.backoff(0, cap), Duration::from_millis(100));
+    /// assert_eq!(base.backoff(2, cap), Duration::from_millis(400));
+    /// assert_eq!(base.backoff(4, cap), Duration::from_secs(1));
+    /// assert_eq!(base.backoff(u32::MAX, cap), Duration::from_secs(1));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn backoff(self, attempt: u32, cap: Duration) -> Duration {
+        let delay = self.checked_mul_pow2(attempt);
+        match (self.as_nanos(), delay.as_nanos(), cap.as_nanos()) {
+            (None, _, _) | (_, _, None) => Self::NONE,
+            (_, Some(d), Some(max)) if d < max => delay,
+            _ => cap,
+        }
+    }
+
+    /// Adds `step` to `self` in place, clamping the result to `max`, and
+    /// returns whether `self` has reached `max`.
+    ///
+    /// This is useful for accumulating small intervals into a bounded total,
+    /// such as the time spent in a wait loop with a ceiling. An addition that
+    /// would overflow is also clamped to `max`.
+    ///
+    /// If `self`, `step`, or `max` is `None`, `self` becomes `None` and this
+    /// returns `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easytime::Duration;
+    ///
+    /// let max = Duration::from_millis(250);
+    /// let mut waited = Duration::ZERO;
+    /// let mut polls = 0;
+    /// while !waited.accumulate(Duration::from_millis(100), max) {
+    ///     polls += 1;
+    /// }
+    /// assert_eq!(polls, 2);
+    /// assert_eq!(waited, max);
+    /// ```
+    pub fn accumulate(&mut self, step: Duration, max: Duration) -> bool {
+        match (self.as_nanos(), step.as_nanos(), max.as_nanos()) {
+            // Both are less than 2^94, so this cannot overflow.
+            (Some(nanos), Some(step), Some(max_nanos)) if nanos + step < max_nanos => {
+                *self = Self(duration_from_nanos_u128(nanos + step));
+                false
+            }
+            (Some(_), Some(_), Some(_)) => {
+                *self = max;
+                true
+            }
+            _ => {
+                *self = Self::NONE;
+                false
+            }
+        }
+    }
+
+    /// Sums the durations in `parts`, clamping the result to `cap`.
+    ///
+    /// Unlike summing with `+`, a total larger than `cap` (including one that
+    /// would overflow) is clamped to `cap` instead of becoming `None`.
+    ///
+    /// Returns [`Duration::NONE`] if `cap` or any element of `parts` is
+    /// `None`, even if the total has already reached `cap`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easytime::Duration;
+    ///
+    /// let cap = Duration::from_secs(10);
+    /// let budget = Duration::sum_capped(&[Duration::from_secs(4), Duration::from_secs(8)], cap);
+    /// assert_eq!(budget, cap);
+    /// assert!(Duration::sum_capped(&[Duration::from_secs(1), Duration::NONE], cap).is_none());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn sum_capped(parts: &[Duration], cap: Duration) -> Duration {
+        let cap_nanos = match cap.as_nanos() {
+            Some(cap) => cap,
+            None => return Self::NONE,
+        };
+        let mut total: u128 = 0;
+        for part in parts {
+            match part.as_nanos() {
+                Some(nanos) => total = total.saturating_add(nanos),
+                None => return Self::NONE,
+            }
+        }
+        if total >= cap_nanos {
+            cap
+        } else {
+            Self(duration_from_nanos_u128(total))
+        }
+    }
+
+    /// Adds two `Duration`s, returning an error instead of `None`.
+    ///
+    /// The error's [`kind`] is [`TryFromTimeErrorKind::Overflow`] if the
+    /// addition overflows, and [`TryFromTimeErrorKind::Unknown`] if either
+    /// operand is already `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easytime::{Duration, TryFromTimeErrorKind};
+    ///
+    /// assert_eq!(Duration::new(0, 1).try_add(Duration::new(0, 1)), Ok(Duration::new(0, 2)));
+    /// let err = Duration::MAX.try_add(Duration::new(0, 1)).unwrap_err();
+    /// assert_eq!(err.kind(), TryFromTimeErrorKind::Overflow);
+    /// ```
+    ///
+    /// [`kind`]: TryFromTimeError::kind
+    #[inline]
+    pub const fn try_add(self, rhs: Duration) -> Result<Duration, TryFromTimeError> {
+        match (self.0, rhs.0) {
+            (Some(lhs), Some(rhs)) => match lhs.checked_add(rhs) {
+                Some(res) => Ok(Self(Some(res))),
+                None => Err(TryFromTimeError(TryFromTimeErrorKind::Overflow)),
+            },
+            _ => Err(TryFromTimeError::new()),
+        }
+    }
+
+    /// Subtracts two `Duration`s, returning an error instead of `None`.
+    ///
+    /// The error's [`kind`] is [`TryFromTimeErrorKind::Underflow`] if the
+    /// result would be negative, and [`TryFromTimeErrorKind::Unknown`] if
+    /// either operand is already `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easytime::{Duration, TryFromTimeErrorKind};
+    ///
+    /// assert_eq!(Duration::new(0, 1).try_sub(Duration::new(0, 0)), Ok(Duration::new(0, 1)));
+    /// let err = Duration::new(0, 0).try_sub(Duration::new(0, 1)).unwrap_err();
+    /// assert_eq!(err.kind(), TryFromTimeErrorKind::Underflow);
+    /// ```
+    ///
+    /// [`kind`]: TryFromTimeError::kind
+    #[inline]
+    pub const fn try_sub(self, rhs: Duration) -> Result<Duration, TryFromTimeError> {
+        match (self.0, rhs.0) {
+            (Some(lhs), Some(rhs)) => match lhs.checked_sub(rhs) {
+                Some(res) => Ok(Self(Some(res))),
+                None => Err(TryFromTimeError(TryFromTimeErrorKind::Underflow)),
+            },
+            _ => Err(TryFromTimeError::new()),
+        }
+    }
+
+    /// Compares two `Duration`s and returns the ordering of `self` relative to
+    /// `other`, together with the absolute difference between them.
+    ///
+    /// If either operand is `None`, returns `(Ordering::Equal, Duration::NONE)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cmp::Ordering;
+    ///
+    /// use easytime::Duration;
+    ///
+    /// let a = Duration::from_secs(3);
+    /// let b = Duration::from_secs(5);
+    /// assert_eq!(a.diff(b), (Ordering::Less, Duration::from_secs(2)));
+    /// assert_eq!(b.diff(a), (Ordering::Greater, Duration::from_secs(2)));
+    /// assert_eq!(a.diff(Duration::NONE), (Ordering::Equal, Duration::NONE));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn diff(self, other: Duration) -> (cmp::Ordering, Duration) {
+        match (self.0, other.0) {
+            (Some(lhs), Some(rhs)) => match lhs.cmp(&rhs) {
+                cmp::Ordering::Less => (cmp::Ordering::Less, other - self),
+                ord => (ord, self - other),
+            },
+            _ => (cmp::Ordering::Equal, Self::NONE),
+        }
+    }
+
+    /// Computes `self * mul / div` without intermediate overflow or precision loss.
+    ///
+    /// The result is rounded down to the nearest nanosecond.
+    ///
+    /// Returns [`Duration::NONE`] if `self` is `None`, `div` is zero, or the
+    /// result overflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easytime::Duration;
+    ///
+    /// // Converts 90 kHz ticks to 48 kHz ticks.
+    /// let dur = Duration::from_secs(2);
+    /// assert_eq!(dur.mul_div(48_000, 90_000), Duration::new(1, 66_666_666));
+    /// // `dur * u32::MAX` would overflow.
+    /// assert_eq!(Duration::MAX.mul_div(u64::MAX, u64::MAX), Duration::MAX);
+    /// assert!(dur.mul_div(1, 0).is_none());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn mul_div(self, mul: u64, div: u64) -> Duration {
+        let (nanos, mul, div) = match self.as_nanos() {
+            Some(nanos) if div != 0 => (nanos, mul as u128, div as u128),
+            _ => return Self::NONE,
+        };
+        // nanos * mul / div = (q * div + r) * mul / div = q * mul + r * mul / div
+        // `r * mul` cannot overflow because both are less than 2^64.
+        let (q, r) = (nanos / div, nanos % div);
+        match q.checked_mul(mul) {
+            Some(n) => match n.checked_add(r * mul / div) {
+                Some(n) => Self(duration_from_nanos_u128(n)),
+                None => Self::NONE,
+            },
+            None => Self::NONE,
+        }
+    }
+
+    /// Multiplies `self` by an integer of any type that can be converted to
+    /// `u64`, such as a `usize` count.
+    ///
+    /// Unlike `Mul<u32>`, this does not require casting the factor, and the
+    /// full `u64` range is supported.
+    ///
+    /// Returns [`Duration::NONE`] if `self` is `None`, `factor` cannot be
+    /// converted to `u64` (e.g., it is negative), or the result overflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easytime::Duration;
+    ///
+    /// let retries: usize = 3;
+    /// assert_eq!(Duration::from_secs(2).checked_scale(retries), Duration::from_secs(6));
+    /// assert!(Duration::from_secs(2).checked_scale(-1_i32).is_none());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn checked_scale<N>(self, factor: N) -> Duration
+    where
+        N: TryInto<u64>,
+    {
+        match factor.try_into() {
+            Ok(factor) => self.mul_div(factor, 1),
+            Err(_) => Self::NONE,
+        }
+    }
+
+    /// Computes `self * percent / 100`, rounded half up to the nearest nanosecond.
+    ///
+    /// The computation is performed in nanoseconds with 128-bit intermediate
+    /// precision, so unlike [`mul_f64`](Self::mul_f64), exact percentages do
+    /// not suffer from floating point imprecision.
+    ///
+    /// Returns [`Duration::NONE`] if `self` is `None` or the result overflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easytime::Duration;
+    ///
+    /// assert_eq!(Duration::from_secs(10).scale_percent(90), Duration::from_secs(9));
+    /// assert_eq!(Duration::from_nanos(5).scale_percent(50), Duration::from_nanos(3));
+    /// assert!(Duration::MAX.scale_percent(101).is_none());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn scale_percent(self, percent: u32) -> Duration {
+        match self.as_nanos() {
+            // `nanos` is less than 2^94, so this cannot overflow.
+            Some(nanos) => Self(duration_from_nanos_u128((nanos * percent as u128 + 50) / 100)),
+            None => Self::NONE,
+        }
+    }
+
+    /// Returns the smallest multiple of `period` that is greater than or equal
+    /// to `self`.
+    ///
+    /// Returns [`Duration::NONE`] if `self` or `period` is `None`, `period` is
+    /// zero, or the result overflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easytime::Duration;
+    ///
+    /// let minute = Duration::from_secs(60);
+    /// assert_eq!(Duration::from_secs(70).align_up(minute), Duration::from_secs(120));
+    /// assert_eq!(Duration::from_secs(120).align_up(minute), Duration::from_secs(120));
+    /// assert!(Duration::from_secs(70).align_up(Duration::ZERO).is_none());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn align_up(self, period: Duration) -> Duration {
+        match (self.as_nanos(), period.as_nanos()) {
+            (Some(nanos), Some(period)) if period != 0 => match nanos % period {
+                0 => self,
+                // Both are less than 2^94, so this cannot overflow.
+                rem => Self(duration_from_nanos_u128(nanos - rem + period)),
+            },
+            _ => Self::NONE,
+        }
+    }
+
+    /// Returns the largest multiple of `period` that is less than or equal to
+    /// `self`.
+    ///
+    /// Returns [`Duration::NONE`] if `self` or `period` is `None`, or `period`
+    /// is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easytime::Duration;
+    ///
+    /// let minute = Duration::from_secs(60);
+    /// assert_eq!(Duration::from_secs(70).align_down(minute), Duration::from_secs(60));
+    /// assert_eq!(Duration::from_secs(120).align_down(minute), Duration::from_secs(120));
+    /// assert!(Duration::from_secs(70).align_down(Duration::ZERO).is_none());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn align_down(self, period: Duration) -> Duration {
+        match (self.as_nanos(), period.as_nanos()) {
+            (Some(nanos), Some(period)) if period != 0 => {
+                Self(duration_from_nanos_u128(nanos - nanos % period))
+            }
+            _ => Self::NONE,
+        }
+    }
+
+    /// Wraps `self` into the range `[0, period)`, e.g., to compute the phase
+    /// of `self` within a repeating cycle of length `period`.
+    ///
+    /// This is the same as `self % period`, but makes the intent explicit.
+    ///
+    /// Returns [`Duration::NONE`] if `self` or `period` is `None`, or `period`
+    /// is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easytime::Duration;
+    ///
+    /// let minute = Duration::from_secs(60);
+    /// assert_eq!(Duration::from_secs(70).wrap(minute), Duration::from_secs(10));
+    /// assert_eq!(Duration::from_secs(50).wrap(minute), Duration::from_secs(50));
+    /// assert!(Duration::from_secs(70).wrap(Duration::ZERO).is_none());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn wrap(self, period: Duration) -> Duration {
+        match (self.as_nanos(), period.as_nanos()) {
+            (Some(nanos), Some(period)) if period != 0 => {
+                Self(duration_from_nanos_u128(nanos % period))
+            }
+            _ => Self::NONE,
+        }
+    }
+
+    /// Returns the greatest common divisor of `self` and `other`, computed on
+    /// their total numbers of nanoseconds.
+    ///
+    /// The greatest common divisor of zero and `d` is `d`.
+    ///
+    /// Returns [`Duration::NONE`] if either operand is `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easytime::Duration;
+    ///
+    /// let gcd = Duration::from_millis(40).gcd(Duration::from_millis(60));
+    /// assert_eq!(gcd, Duration::from_millis(20));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn gcd(self, other: Duration) -> Duration {
+        match (self.as_nanos(), other.as_nanos()) {
+            (Some(a), Some(b)) => Self(duration_from_nanos_u128(gcd_u128(a, b))),
+            _ => Self::NONE,
+        }
+    }
+
+    /// Returns the least common multiple of `self` and `other`, computed on
+    /// their total numbers of nanoseconds.
+    ///
+    /// This is the length of the combined cycle of two periods. The least
+    /// common multiple of zero and `d` is zero.
+    ///
+    /// Returns [`Duration::NONE`] if either operand is `None` or the result
+    /// overflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easytime::Duration;
+    ///
+    /// let lcm = Duration::from_millis(40).lcm(Duration::from_millis(60));
+    /// assert_eq!(lcm, Duration::from_millis(120));
+    /// assert!(Duration::MAX.lcm(Duration::MAX - Duration::from_nanos(1)).is_none());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn lcm(self, other: Duration) -> Duration {
+        match (self.as_nanos(), other.as_nanos()) {
+            (Some(0), Some(_)) | (Some(_), Some(0)) => Self::ZERO,
+            (Some(a), Some(b)) => match (a / gcd_u128(a, b)).checked_mul(b) {
+                Some(nanos) => Self(duration_from_nanos_u128(nanos)),
+                None => Self::NONE,
+            },
+            _ => Self::NONE,
+        }
+    }
+
+    /// Adds a signed offset of `delta` nanoseconds to this `Duration`.
+    ///
+    /// Returns [`Duration::NONE`] if `self` is `None`, or if the result is
+    /// negative or larger than [`Duration::MAX`]. Negative results are not
+    /// clamped to zero.
     ///
     /// # Examples
     ///
     /// ```
     /// use easytime::Duration;
     ///
-    /// let dur = Duration::new(2, 700_000_000);
-    /// assert_eq!(dur.div_f64(3.14), Duration::new(0, 859_872_611));
-    /// // note that truncation is used, not rounding
-    /// assert_eq!(dur.div_f64(3.14e5), Duration::new(0, 8_598));
+    /// let dur = Duration::from_millis(10);
+    /// assert_eq!(dur.offset_nanos(500), Duration::new(0, 10_000_500));
+    /// assert_eq!(dur.offset_nanos(-500), Duration::new(0, 9_999_500));
+    /// assert!(dur.offset_nanos(-10_000_001).is_none());
     /// ```
     #[inline]
     #[must_use]
-    pub fn div_f32(self, rhs: f32) -> Duration {
-        self.as_secs_f32().map_or(Self::NONE, |secs| Duration::from_secs_f32(secs / rhs))
+    #[allow(clippy::cast_possible_wrap)] // `as_nanos` is less than 2^94, so it fits in `i128`.
+    pub const fn offset_nanos(self, delta: i64) -> Duration {
+        match self.as_nanos() {
+            Some(nanos) => {
+                let nanos = nanos as i128 + delta as i128;
+                if nanos < 0 {
+                    return Self::NONE;
+                }
+                Self(duration_from_nanos_u128(nanos as u128))
+            }
+            None => Self::NONE,
+        }
     }
 
-    // TODO: div_duration https://github.com/rust-lang/rust/issues/63139 / stabilized in 1.80 https://github.com/rust-lang/rust/pull/124667
-    // TODO: duration_consts_float stabilized in 1.83 https://github.com/rust-lang/rust/pull/131289
-    // /// Divides `Duration` by `Duration` and returns `f64`.
-    // ///
-    // /// # Examples
-    // ///
-    // /// ```
-    // /// use easytime::Duration;
-    // ///
-    // /// let dur1 = Duration::new(2, 700_000_000);
-    // /// let dur2 = Duration::new(5, 400_000_000);
-    // /// assert_eq!(dur1.div_duration_f64(dur2), 0.5);
-    // /// ```
-    // #[inline]
-    // #[must_use]
-    // pub fn div_duration_f64(self, rhs: Duration) -> f64 {
-    //     let self_nanos =
-    //         (self.secs as f64) * (NANOS_PER_SEC as f64) + (self.nanos.as_inner() as f64);
-    //     let rhs_nanos = (rhs.secs as f64) * (NANOS_PER_SEC as f64) + (rhs.nanos.as_inner() as f64);
-    //     self_nanos / rhs_nanos
-    // }
-
-    // TODO: div_duration https://github.com/rust-lang/rust/issues/63139 / stabilized in 1.80 https://github.com/rust-lang/rust/pull/124667
-    // TODO: duration_consts_float stabilized in 1.83 https://github.com/rust-lang/rust/pull/131289
-    // /// Divides `Duration` by `Duration` and returns `f32`.
-    // ///
-    // /// # Examples
-    // ///
-    // /// ```
-    // /// use easytime::Duration;
-    // ///
-    // /// let dur1 = Duration::new(2, 700_000_000);
-    // /// let dur2 = Duration::new(5, 400_000_000);
-    // /// assert_eq!(dur1.div_duration_f32(dur2), 0.5);
-    // /// ```
-    // #[inline]
-    // #[must_use]
-    // pub fn div_duration_f32(self, rhs: Duration) -> f32 {
-    //     let self_nanos =
-    //         (self.secs as f32) * (NANOS_PER_SEC as f32) + (self.nanos.as_inner() as f32);
-    //     let rhs_nanos = (rhs.secs as f32) * (NANOS_PER_SEC as f32) + (rhs.nanos.as_inner() as f32);
-    //     self_nanos / rhs_nanos
-    // }
-
     // -------------------------------------------------------------------------
     // Option based method implementations
 
@@ -622,8 +2367,52 @@ impl Duration {
         !self.is_some()
     }
 
+    /// Returns `true` if this `Duration` is a valid duration, i.e., it is not
+    /// poisoned by an earlier overflow or invalid operation.
+    ///
+    /// This is an alias for [`is_some`](Self::is_some).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easytime::Duration;
+    ///
+    /// let timeout = Duration::from_secs(1) * 3;
+    /// assert!(timeout.is_valid());
+    /// assert!(!(Duration::MAX * 2).is_valid());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn is_valid(&self) -> bool {
+        self.is_some()
+    }
+
+    /// Returns `true` if this `Duration` is poisoned, i.e., it is the result
+    /// of an overflow or invalid operation.
+    ///
+    /// This is an alias for [`is_none`](Self::is_none).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easytime::Duration;
+    ///
+    /// let elapsed = Duration::from_secs(1) - Duration::from_secs(2);
+    /// assert!(elapsed.is_poison());
+    /// assert!(!Duration::ZERO.is_poison());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn is_poison(&self) -> bool {
+        self.is_none()
+    }
+
     /// Returns the contained [`std::time::Duration`] or `None`.
     ///
+    /// This is the canonical way to get the inner value. See also
+    /// [`into_option`](Self::into_option), which is an alias that may read
+    /// better in code using the `?` operator.
+    ///
     /// # Examples
     ///
     /// ```
@@ -640,6 +2429,84 @@ impl Duration {
         self.0
     }
 
+    /// Returns the contained [`std::time::Duration`] or `None`.
+    ///
+    /// This is an alias for [`into_inner`](Self::into_inner), named for use
+    /// with the `?` operator in functions returning `Option`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time;
+    ///
+    /// use easytime::Duration;
+    ///
+    /// fn remaining(total: Duration, elapsed: Duration) -> Option<time::Duration> {
+    ///     let d = (total - elapsed).into_option()?;
+    ///     Some(d)
+    /// }
+    ///
+    /// assert_eq!(
+    ///     remaining(Duration::from_secs(3), Duration::from_secs(1)),
+    ///     Some(time::Duration::from_secs(2))
+    /// );
+    /// assert_eq!(remaining(Duration::from_secs(1), Duration::from_secs(3)), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn into_option(self) -> Option<time::Duration> {
+        self.0
+    }
+
+    /// Returns a reference to the contained [`std::time::Duration`] or `None`.
+    ///
+    /// Unlike [`into_inner`], this borrows the value instead of copying it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easytime::Duration;
+    ///
+    /// let dur = Duration::new(1, 0);
+    /// if let Some(d) = dur.as_std() {
+    ///     assert_eq!(d.as_secs(), 1);
+    /// }
+    /// assert_eq!(Duration::NONE.as_std(), None);
+    /// ```
+    ///
+    /// [`into_inner`]: Self::into_inner
+    #[inline]
+    #[must_use]
+    pub const fn as_std(&self) -> Option<&time::Duration> {
+        self.0.as_ref()
+    }
+
+    /// Returns a mutable reference to the contained [`std::time::Duration`]
+    /// or `None`.
+    ///
+    /// Every [`std::time::Duration`] is valid, so mutating through this
+    /// reference cannot produce an overflowed value. A `None` duration stays
+    /// `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time;
+    ///
+    /// use easytime::Duration;
+    ///
+    /// let mut dur = Duration::from_secs(90);
+    /// if let Some(d) = dur.as_std_mut() {
+    ///     *d = (*d).min(time::Duration::from_secs(60));
+    /// }
+    /// assert_eq!(dur, Duration::from_secs(60));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn as_std_mut(&mut self) -> Option<&mut time::Duration> {
+        self.0.as_mut()
+    }
+
     /// Returns the contained [`std::time::Duration`] or a default.
     ///
     /// `dur.unwrap_or(default)` is equivalent to `dur.into_inner().unwrap_or(default)`.
@@ -690,12 +2557,55 @@ impl Duration {
     /// );
     /// ```
     #[inline]
+    #[must_use]
     pub fn unwrap_or_else<F>(self, default: F) -> time::Duration
     where
         F: FnOnce() -> time::Duration,
     {
         self.0.unwrap_or_else(default)
     }
+
+    /// Returns the contained [`std::time::Duration`] or [`std::time::Duration::ZERO`].
+    ///
+    /// `dur.unwrap_or_zero()` is equivalent to `dur.unwrap_or(std::time::Duration::ZERO)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easytime::Duration;
+    ///
+    /// let zero = Duration::new(0, 0);
+    /// let one_sec = Duration::new(1, 0);
+    /// assert_eq!((one_sec - zero).unwrap_or_zero(), std::time::Duration::from_secs(1));
+    /// assert_eq!((zero - one_sec).unwrap_or_zero(), std::time::Duration::ZERO);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn unwrap_or_zero(self) -> time::Duration {
+        self.unwrap_or(time::Duration::ZERO)
+    }
+
+    /// Converts this `Duration` into [`std::time::Duration`], mapping `None`
+    /// to [`std::time::Duration::ZERO`].
+    ///
+    /// This is a lossy fallback: whether the `None` was caused by an overflow
+    /// or an underflow is not tracked, so it cannot be saturated to
+    /// [`std::time::Duration::MAX`]. Use [`TryFrom`] if `None` must be handled
+    /// as an error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easytime::Duration;
+    ///
+    /// assert_eq!(Duration::from_secs(1).saturating_into_std(), std::time::Duration::from_secs(1));
+    /// assert_eq!(Duration::NONE.saturating_into_std(), std::time::Duration::ZERO);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn saturating_into_std(self) -> time::Duration {
+        self.unwrap_or_zero()
+    }
 }
 
 // -----------------------------------------------------------------------------
@@ -725,6 +2635,62 @@ impl PartialOrd<Duration> for time::Duration {
     }
 }
 
+impl PartialEq<Option<time::Duration>> for Duration {
+    fn eq(&self, other: &Option<time::Duration>) -> bool {
+        self.0 == *other
+    }
+}
+
+impl PartialEq<Duration> for Option<time::Duration> {
+    fn eq(&self, other: &Duration) -> bool {
+        other.eq(self)
+    }
+}
+
+/// Compares a `Duration` with an `Option<std::time::Duration>` in the same
+/// way as [`Ord`] for `Duration`, i.e., `None` is less than any other value.
+impl PartialOrd<Option<time::Duration>> for Duration {
+    fn partial_cmp(&self, other: &Option<time::Duration>) -> Option<cmp::Ordering> {
+        self.0.partial_cmp(other)
+    }
+}
+
+/// Compares an `Option<std::time::Duration>` with a `Duration` in the same
+/// way as [`Ord`] for `Duration`, i.e., `None` is less than any other value.
+impl PartialOrd<Duration> for Option<time::Duration> {
+    fn partial_cmp(&self, other: &Duration) -> Option<cmp::Ordering> {
+        self.partial_cmp(&other.0)
+    }
+}
+
+/// Compares a `Duration` with a number of **nanoseconds**.
+impl PartialEq<u64> for Duration {
+    fn eq(&self, other: &u64) -> bool {
+        self.as_nanos() == Some(u128::from(*other))
+    }
+}
+
+/// Compares a number of **nanoseconds** with a `Duration`.
+impl PartialEq<Duration> for u64 {
+    fn eq(&self, other: &Duration) -> bool {
+        other.eq(self)
+    }
+}
+
+/// Compares a `Duration` with a number of **nanoseconds**.
+impl PartialOrd<u64> for Duration {
+    fn partial_cmp(&self, other: &u64) -> Option<cmp::Ordering> {
+        self.as_nanos().map(|this| this.cmp(&u128::from(*other)))
+    }
+}
+
+/// Compares a number of **nanoseconds** with a `Duration`.
+impl PartialOrd<Duration> for u64 {
+    fn partial_cmp(&self, other: &Duration) -> Option<cmp::Ordering> {
+        other.as_nanos().map(|other| u128::from(*self).cmp(&other))
+    }
+}
+
 impl fmt::Debug for Duration {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         fmt::Debug::fmt(&self.0, f)
@@ -737,23 +2703,84 @@ impl Default for Duration {
     }
 }
 
+impl AsRef<Option<time::Duration>> for Duration {
+    fn as_ref(&self) -> &Option<time::Duration> {
+        &self.0
+    }
+}
+
+/// Creates a `Duration` from a [`core::time::Duration`] (also known as
+/// [`std::time::Duration`]).
+///
+/// This conversion is available in `no_std` environments.
 impl From<time::Duration> for Duration {
     fn from(dur: time::Duration) -> Self {
         Self(Some(dur))
     }
 }
 
+/// Creates a `Duration` from an `Option<core::time::Duration>`, mapping `None`
+/// to [`Duration::NONE`].
 impl From<Option<time::Duration>> for Duration {
     fn from(dur: Option<time::Duration>) -> Self {
         Self(dur)
     }
 }
 
+/// Converts a `Duration` into an `Option<core::time::Duration>`.
+///
+/// This is equivalent to [`Duration::into_inner`].
+impl From<Duration> for Option<time::Duration> {
+    fn from(dur: Duration) -> Self {
+        dur.0
+    }
+}
+
+/// Creates a `Duration` from a `(secs, nanos)` pair.
+///
+/// This is equivalent to [`Duration::new`], so nanoseconds greater than one
+/// billion carry over into the seconds, and the result is `None` if it
+/// overflows.
+impl From<(u64, u32)> for Duration {
+    fn from((secs, nanos): (u64, u32)) -> Self {
+        Self::new(secs, nanos)
+    }
+}
+
+/// Converts a `Duration` into a [`core::time::Duration`] (also known as
+/// [`std::time::Duration`]).
+///
+/// Returns an error if the `Duration` is `None`. This conversion is available
+/// in `no_std` environments.
 impl TryFrom<Duration> for time::Duration {
     type Error = TryFromTimeError;
 
     fn try_from(dur: Duration) -> Result<Self, Self::Error> {
-        dur.into_inner().ok_or(TryFromTimeError(()))
+        dur.into_inner().ok_or(TryFromTimeError::new())
+    }
+}
+
+/// Converts a `Duration` into the total number of nanoseconds it contains.
+///
+/// Returns an error whose kind is [`TryFromTimeErrorKind::OutOfRange`] if the
+/// `Duration` is `None`.
+impl TryFrom<Duration> for u128 {
+    type Error = TryFromTimeError;
+
+    fn try_from(dur: Duration) -> Result<Self, Self::Error> {
+        dur.as_nanos().ok_or(TryFromTimeError(TryFromTimeErrorKind::OutOfRange))
+    }
+}
+
+/// Converts a `Duration` into the number of seconds it contains, as `f64`.
+///
+/// Returns an error whose kind is [`TryFromTimeErrorKind::OutOfRange`] if the
+/// `Duration` is `None`.
+impl TryFrom<Duration> for f64 {
+    type Error = TryFromTimeError;
+
+    fn try_from(dur: Duration) -> Result<Self, Self::Error> {
+        dur.as_secs_f64().ok_or(TryFromTimeError(TryFromTimeErrorKind::OutOfRange))
     }
 }
 
@@ -849,6 +2876,116 @@ impl DivAssign<u32> for Duration {
     }
 }
 
+impl Rem for Duration {
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        Self(pair_and_then(self.0, rhs.0, checked_rem))
+    }
+}
+
+impl Rem<time::Duration> for Duration {
+    type Output = Self;
+
+    fn rem(self, rhs: time::Duration) -> Self::Output {
+        Self(self.0.and_then(|lhs| checked_rem(lhs, rhs)))
+    }
+}
+
+impl RemAssign for Duration {
+    fn rem_assign(&mut self, rhs: Self) {
+        *self = *self % rhs;
+    }
+}
+
+impl RemAssign<time::Duration> for Duration {
+    fn rem_assign(&mut self, rhs: time::Duration) {
+        *self = *self % rhs;
+    }
+}
+
+/// Computes the total of the durations, or `None` if any element is `None`
+/// or the total overflows.
+///
+/// # Examples
+///
+/// ```
+/// use easytime::Duration;
+///
+/// let total: Duration = [Duration::from_secs(1), Duration::from_millis(500)].into_iter().collect();
+/// assert_eq!(total, Duration::from_millis(1_500));
+/// ```
+impl FromIterator<Duration> for Duration {
+    fn from_iter<I: IntoIterator<Item = Duration>>(iter: I) -> Self {
+        let mut total = Self::ZERO;
+        total.extend(iter);
+        total
+    }
+}
+
+/// Adds the durations to this duration, which becomes `None` if any element
+/// is `None` or the total overflows.
+impl Extend<Duration> for Duration {
+    fn extend<I: IntoIterator<Item = Duration>>(&mut self, iter: I) {
+        for dur in iter {
+            *self += dur;
+        }
+    }
+}
+
 // TODO: duration_sum
 // impl Sum for Duration
 // impl<'a> Sum<&'a Duration> for Duration
+
+/// How [`Duration::from_secs_f64_rounded`] rounds the sub-nanosecond
+/// remainder.
+///
+/// Durations are never negative, so rounding toward zero ([`Trunc`](Self::Trunc))
+/// and toward negative infinity ([`Down`](Self::Down)) give the same result.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RoundMode {
+    /// Rounds toward zero.
+    Trunc,
+    /// Rounds to the nearest nanosecond, with ties rounded to even.
+    Nearest,
+    /// Rounds toward positive infinity.
+    Up,
+    /// Rounds toward negative infinity.
+    Down,
+}
+
+/// A unit of time used by [`Duration::format_as`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DurationUnit {
+    /// Nanoseconds (`ns`).
+    Nanos,
+    /// Microseconds (`µs`).
+    Micros,
+    /// Milliseconds (`ms`).
+    Millis,
+    /// Seconds (`s`).
+    Secs,
+}
+
+/// Helper struct for formatting a [`Duration`] without the `Some(...)` wrapper.
+///
+/// This is created by [`Duration::display_inner`].
+#[derive(Clone, Copy)]
+pub struct DisplayInner(Duration);
+
+impl fmt::Debug for DisplayInner {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &(self.0).0 {
+            Some(d) => fmt::Debug::fmt(d, f),
+            None => f.pad("None"),
+        }
+    }
+}
+
+impl fmt::Display for DisplayInner {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
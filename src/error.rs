@@ -3,15 +3,69 @@
 use core::fmt;
 
 /// The error type returned when a conversion from `easytime` types to `std::time` types fails.
+///
+/// The cause of the error can be obtained with [`kind`](Self::kind).
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct TryFromTimeError(pub(crate) ());
+pub struct TryFromTimeError(pub(crate) TryFromTimeErrorKind);
+
+impl TryFromTimeError {
+    /// Creates an error whose specific cause is not known.
+    pub(crate) const fn new() -> Self {
+        Self(TryFromTimeErrorKind::Unknown)
+    }
+
+    /// Returns the cause of this error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easytime::{Instant, TryFromTimeErrorKind};
+    ///
+    /// let now = Instant::now();
+    /// let earlier = now - easytime::Duration::from_secs(1);
+    /// let err = earlier.try_duration_since(now).unwrap_err();
+    /// assert_eq!(err.kind(), TryFromTimeErrorKind::Underflow);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn kind(self) -> TryFromTimeErrorKind {
+        self.0
+    }
+}
 
 impl fmt::Display for TryFromTimeError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_str("invalid arithmetic attempted on instants or durations")
+        f.write_str(match self.0 {
+            TryFromTimeErrorKind::Overflow => "overflow when computing instants or durations",
+            TryFromTimeErrorKind::Underflow => "underflow when computing instants or durations",
+            TryFromTimeErrorKind::OutOfRange => "value is out of range for instants or durations",
+            TryFromTimeErrorKind::PrecisionLoss => {
+                "value cannot be represented exactly in the target type"
+            }
+            TryFromTimeErrorKind::Unknown => {
+                "invalid arithmetic attempted on instants or durations"
+            }
+        })
     }
 }
 
 #[cfg(feature = "std")]
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 impl std::error::Error for TryFromTimeError {}
+
+/// The cause of a [`TryFromTimeError`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TryFromTimeErrorKind {
+    /// The result of the arithmetic was larger than the maximum value.
+    Overflow,
+    /// The result of the arithmetic was smaller than the minimum value.
+    Underflow,
+    /// The value to be converted was out of range for the target type.
+    OutOfRange,
+    /// The value to be converted cannot be represented exactly in the target
+    /// type.
+    PrecisionLoss,
+    /// The value was `None`, and the operation that caused it is not known.
+    Unknown,
+}
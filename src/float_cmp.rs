@@ -0,0 +1,36 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use ::float_cmp::{ApproxEq, F64Margin};
+
+use crate::Duration;
+
+/// Compares durations by their [`as_secs_f64`](Duration::as_secs_f64) values,
+/// within an epsilon (in seconds) or a number of ULPs.
+///
+/// Comparisons involving `None` are always `false`.
+///
+/// Note that [`Duration::approx_eq`] is an inherent method with a different
+/// signature, so this must be called as `ApproxEq::approx_eq(a, b, margin)`.
+///
+/// # Examples
+///
+/// ```
+/// use easytime::Duration;
+/// use float_cmp::ApproxEq;
+///
+/// let a = Duration::from_secs(1);
+/// let b = a + Duration::from_nanos(1);
+/// assert!(ApproxEq::approx_eq(a, b, (1e-6, 0)));
+/// assert!(!ApproxEq::approx_eq(a, Duration::NONE, (1e-6, 0)));
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "float-cmp")))]
+impl ApproxEq for Duration {
+    type Margin = F64Margin;
+
+    fn approx_eq<M: Into<Self::Margin>>(self, other: Self, margin: M) -> bool {
+        match (self.as_secs_f64(), other.as_secs_f64()) {
+            (Some(lhs), Some(rhs)) => lhs.approx_eq(rhs, margin),
+            _ => false,
+        }
+    }
+}
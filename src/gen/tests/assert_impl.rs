@@ -16,19 +16,123 @@ fn assert_unpin<T: ?Sized + Unpin>() {}
 fn assert_unwind_safe<T: ?Sized + std::panic::UnwindSafe>() {}
 fn assert_ref_unwind_safe<T: ?Sized + std::panic::RefUnwindSafe>() {}
 const _: fn() = || {
+    #[cfg(feature = "std")]
+    {
+        assert_send::<crate::clock::SystemClock>();
+        assert_sync::<crate::clock::SystemClock>();
+        assert_unpin::<crate::clock::SystemClock>();
+        assert_unwind_safe::<crate::clock::SystemClock>();
+        assert_ref_unwind_safe::<crate::clock::SystemClock>();
+    }
+    #[cfg(feature = "std")]
+    {
+        assert_send::<crate::deadline::Deadline>();
+        assert_sync::<crate::deadline::Deadline>();
+        assert_unpin::<crate::deadline::Deadline>();
+        assert_unwind_safe::<crate::deadline::Deadline>();
+        assert_ref_unwind_safe::<crate::deadline::Deadline>();
+    }
+    assert_send::<crate::backoff::Backoff>();
+    assert_sync::<crate::backoff::Backoff>();
+    assert_unpin::<crate::backoff::Backoff>();
+    assert_unwind_safe::<crate::backoff::Backoff>();
+    assert_ref_unwind_safe::<crate::backoff::Backoff>();
+    assert_send::<crate::duration::DisplayInner>();
+    assert_sync::<crate::duration::DisplayInner>();
+    assert_unpin::<crate::duration::DisplayInner>();
+    assert_unwind_safe::<crate::duration::DisplayInner>();
+    assert_ref_unwind_safe::<crate::duration::DisplayInner>();
     assert_send::<crate::duration::Duration>();
     assert_sync::<crate::duration::Duration>();
     assert_unpin::<crate::duration::Duration>();
     assert_unwind_safe::<crate::duration::Duration>();
     assert_ref_unwind_safe::<crate::duration::Duration>();
+    assert_send::<crate::duration::DurationUnit>();
+    assert_sync::<crate::duration::DurationUnit>();
+    assert_unpin::<crate::duration::DurationUnit>();
+    assert_unwind_safe::<crate::duration::DurationUnit>();
+    assert_ref_unwind_safe::<crate::duration::DurationUnit>();
+    assert_send::<crate::duration::RoundMode>();
+    assert_sync::<crate::duration::RoundMode>();
+    assert_unpin::<crate::duration::RoundMode>();
+    assert_unwind_safe::<crate::duration::RoundMode>();
+    assert_ref_unwind_safe::<crate::duration::RoundMode>();
     assert_send::<crate::error::TryFromTimeError>();
     assert_sync::<crate::error::TryFromTimeError>();
     assert_unpin::<crate::error::TryFromTimeError>();
     assert_unwind_safe::<crate::error::TryFromTimeError>();
     assert_ref_unwind_safe::<crate::error::TryFromTimeError>();
-    assert_send::<crate::instant::Instant>();
-    assert_sync::<crate::instant::Instant>();
-    assert_unpin::<crate::instant::Instant>();
-    assert_unwind_safe::<crate::instant::Instant>();
-    assert_ref_unwind_safe::<crate::instant::Instant>();
+    assert_send::<crate::error::TryFromTimeErrorKind>();
+    assert_sync::<crate::error::TryFromTimeErrorKind>();
+    assert_unpin::<crate::error::TryFromTimeErrorKind>();
+    assert_unwind_safe::<crate::error::TryFromTimeErrorKind>();
+    assert_ref_unwind_safe::<crate::error::TryFromTimeErrorKind>();
+    assert_send::<crate::policy::DurationOps>();
+    assert_sync::<crate::policy::DurationOps>();
+    assert_unpin::<crate::policy::DurationOps>();
+    assert_unwind_safe::<crate::policy::DurationOps>();
+    assert_ref_unwind_safe::<crate::policy::DurationOps>();
+    assert_send::<crate::policy::OverflowPolicy>();
+    assert_sync::<crate::policy::OverflowPolicy>();
+    assert_unpin::<crate::policy::OverflowPolicy>();
+    assert_unwind_safe::<crate::policy::OverflowPolicy>();
+    assert_ref_unwind_safe::<crate::policy::OverflowPolicy>();
+    #[cfg(feature = "std")]
+    {
+        assert_send::<crate::instant::Instant>();
+        assert_sync::<crate::instant::Instant>();
+        assert_unpin::<crate::instant::Instant>();
+        assert_unwind_safe::<crate::instant::Instant>();
+        assert_ref_unwind_safe::<crate::instant::Instant>();
+    }
+    #[cfg(feature = "std")]
+    {
+        assert_send::<crate::interval::Interval>();
+        assert_sync::<crate::interval::Interval>();
+        assert_unpin::<crate::interval::Interval>();
+        assert_unwind_safe::<crate::interval::Interval>();
+        assert_ref_unwind_safe::<crate::interval::Interval>();
+    }
+    assert_send::<crate::raw_instant::RawInstant>();
+    assert_sync::<crate::raw_instant::RawInstant>();
+    assert_unpin::<crate::raw_instant::RawInstant>();
+    assert_unwind_safe::<crate::raw_instant::RawInstant>();
+    assert_ref_unwind_safe::<crate::raw_instant::RawInstant>();
+    assert_send::<crate::ticker::Ticker>();
+    assert_sync::<crate::ticker::Ticker>();
+    assert_unpin::<crate::ticker::Ticker>();
+    assert_unwind_safe::<crate::ticker::Ticker>();
+    assert_ref_unwind_safe::<crate::ticker::Ticker>();
+    #[cfg(feature = "rand")]
+    {
+        assert_send::<crate::rand::DurationRange>();
+        assert_sync::<crate::rand::DurationRange>();
+        assert_unpin::<crate::rand::DurationRange>();
+        assert_unwind_safe::<crate::rand::DurationRange>();
+        assert_ref_unwind_safe::<crate::rand::DurationRange>();
+    }
+    #[cfg(feature = "std")]
+    {
+        assert_send::<crate::rate::RateCounter>();
+        assert_sync::<crate::rate::RateCounter>();
+        assert_unpin::<crate::rate::RateCounter>();
+        assert_unwind_safe::<crate::rate::RateCounter>();
+        assert_ref_unwind_safe::<crate::rate::RateCounter>();
+    }
+    #[cfg(feature = "std")]
+    {
+        assert_send::<crate::stopwatch::Stopwatch>();
+        assert_sync::<crate::stopwatch::Stopwatch>();
+        assert_unpin::<crate::stopwatch::Stopwatch>();
+        assert_unwind_safe::<crate::stopwatch::Stopwatch>();
+        assert_ref_unwind_safe::<crate::stopwatch::Stopwatch>();
+    }
+    #[cfg(feature = "tokio")]
+    {
+        assert_send::<crate::tokio::TimeoutError>();
+        assert_sync::<crate::tokio::TimeoutError>();
+        assert_unpin::<crate::tokio::TimeoutError>();
+        assert_unwind_safe::<crate::tokio::TimeoutError>();
+        assert_ref_unwind_safe::<crate::tokio::TimeoutError>();
+    }
 };
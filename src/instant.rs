@@ -6,7 +6,10 @@ use core::{
 };
 use std::time;
 
-use crate::{utils::pair_and_then, Duration, TryFromTimeError};
+use crate::{
+    duration::duration_from_nanos_u128, utils::pair_and_then, Duration, TryFromTimeError,
+    TryFromTimeErrorKind,
+};
 
 /// A measurement of a monotonically nondecreasing clock.
 /// Opaque and useful only with `Duration`.
@@ -63,6 +66,278 @@ impl Instant {
         Self(Some(time::Instant::now()))
     }
 
+    /// Returns an instant corresponding to "now", or `None` if a monotonic
+    /// clock is not available on this platform.
+    ///
+    /// [`now`](Self::now) panics on platforms where the standard library does
+    /// not implement a monotonic clock. This function returns `None` instead,
+    /// so that code that only uses time for diagnostics or timeouts can
+    /// degrade gracefully.
+    ///
+    /// Availability is determined at compile time:
+    ///
+    /// | Target                               | Returns         |
+    /// | ------------------------------------ | --------------- |
+    /// | `wasm32-unknown-unknown`, `wasm64-unknown-unknown` | `None` |
+    /// | All other targets supported by `std` | same as `now()` |
+    ///
+    /// Note that on `wasm*-unknown-unknown`, crates like `web-time` can be used
+    /// to access the clock provided by the JavaScript host.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easytime::Instant;
+    ///
+    /// let now = Instant::try_now();
+    /// if cfg!(not(all(target_family = "wasm", target_os = "unknown"))) {
+    ///     assert!(now.is_some());
+    /// }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn try_now() -> Self {
+        #[cfg(all(target_family = "wasm", target_os = "unknown"))]
+        {
+            Self::NONE
+        }
+        #[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
+        {
+            Self::now()
+        }
+    }
+
+    /// Returns the nominal granularity of the clock used by [`now`](Self::now),
+    /// i.e., the unit in which the platform reports instants.
+    ///
+    /// This helps to decide whether sub-microsecond timing is meaningful on the
+    /// current platform. The value is a per-platform constant based on the
+    /// clock that `std` uses and typical hardware, not a measurement. It is
+    /// not a guarantee: the actual resolution may be much coarser on a
+    /// particular system. For example, Linux kernels without high-resolution
+    /// timers and some BSDs advance `CLOCK_MONOTONIC` only on each timer tick.
+    ///
+    /// | Platform                             | Clock                        | Granularity |
+    /// | ------------------------------------ | ---------------------------- | ----------- |
+    /// | Linux, Android, and other Unix       | `CLOCK_MONOTONIC`            | 1 ns        |
+    /// | macOS, iOS, and other Apple targets  | `CLOCK_UPTIME_RAW`           | 42 ns       |
+    /// | Windows                              | `QueryPerformanceCounter`    | 100 ns      |
+    /// | `wasm32-unknown-unknown`, `wasm64-unknown-unknown` | (unavailable)  | `None`      |
+    /// | All other targets supported by `std` |                              | 1 ms        |
+    ///
+    /// On Apple Silicon, the clock ticks at 24 MHz (about 41.7 ns). On Windows,
+    /// `QueryPerformanceCounter` typically runs at 10 MHz. The value for other
+    /// targets is a rough guess.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easytime::{Duration, Instant};
+    ///
+    /// let resolution = Instant::resolution();
+    /// if resolution > Duration::from_micros(1) {
+    ///     // sub-microsecond timing is not meaningful
+    /// }
+    /// ```
+    #[must_use]
+    pub const fn resolution() -> Duration {
+        #[cfg(all(target_family = "wasm", target_os = "unknown"))]
+        {
+            Duration::NONE
+        }
+        #[cfg(target_vendor = "apple")]
+        {
+            Duration::from_nanos(42)
+        }
+        #[cfg(windows)]
+        {
+            Duration::from_nanos(100)
+        }
+        #[cfg(all(unix, not(target_vendor = "apple")))]
+        {
+            Duration::from_nanos(1)
+        }
+        #[cfg(not(any(unix, windows, all(target_family = "wasm", target_os = "unknown"))))]
+        {
+            Duration::from_millis(1)
+        }
+    }
+
+    /// Returns an instant corresponding to `dur` after "now".
+    ///
+    /// This is equivalent to `Instant::now() + dur`, and returns `None` if the
+    /// result overflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easytime::{Duration, Instant};
+    ///
+    /// let deadline = Instant::after(Duration::from_secs(1));
+    /// assert!(deadline > Instant::now());
+    /// assert!(Instant::after(Duration::NONE).is_none());
+    /// ```
+    #[must_use]
+    pub fn after(dur: Duration) -> Self {
+        Self::now() + dur
+    }
+
+    /// Returns an instant corresponding to `dur` before "now".
+    ///
+    /// This is equivalent to `Instant::now() - dur`, and returns `None` if the
+    /// result overflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easytime::{Duration, Instant};
+    ///
+    /// let earlier = Instant::before(Duration::from_millis(1));
+    /// assert!(earlier < Instant::now());
+    /// ```
+    #[must_use]
+    pub fn before(dur: Duration) -> Self {
+        Self::now() - dur
+    }
+
+    /// Returns an instant corresponding to `offset` after `base`.
+    ///
+    /// This is equivalent to `base + offset`, and returns `None` if the result
+    /// overflows.
+    ///
+    /// [`std::time::Instant`] cannot be constructed from raw values, so this
+    /// is the recommended way to build deterministic instants in tests: take a
+    /// single `base` (e.g., from [`Instant::now`]) and derive every other
+    /// instant from it, so that durations between them are exact and do not
+    /// depend on how long the test takes to run.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easytime::{Duration, Instant};
+    ///
+    /// let base = Instant::now();
+    /// let a = Instant::from_base_offset(base, Duration::from_secs(1));
+    /// let b = Instant::from_base_offset(base, Duration::from_secs(3));
+    /// assert_eq!(b.duration_since(a), Duration::from_secs(2));
+    /// ```
+    #[cfg(feature = "mock")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "mock")))]
+    #[must_use]
+    pub fn from_base_offset(base: Self, offset: Duration) -> Self {
+        base + offset
+    }
+
+    /// Returns the instant `dur` after this one, clamped to the latest instant
+    /// the platform can represent.
+    ///
+    /// [`std::time::Instant`] does not expose its bounds, so the nearest valid
+    /// instant is found by searching with [`checked_add`]. If `self + dur`
+    /// does not overflow, this is the same as `self + dur`.
+    ///
+    /// Returns `None` if this instant or `dur` is `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easytime::{Duration, Instant};
+    ///
+    /// let now = Instant::now();
+    /// assert!((now + Duration::MAX).is_none());
+    /// let max = now.saturating_add(Duration::MAX);
+    /// assert!(max > now);
+    /// assert!((max + Duration::from_nanos(1)).is_none());
+    /// ```
+    ///
+    /// [`checked_add`]: std::time::Instant::checked_add
+    #[inline]
+    #[must_use]
+    pub fn saturating_add(&self, dur: Duration) -> Self {
+        Self(pair_and_then(self.0, dur.0, |this, dur| {
+            Some(saturate(this, dur, time::Instant::checked_add))
+        }))
+    }
+
+    /// Returns the instant `dur` before this one, clamped to the earliest
+    /// instant the platform can represent.
+    ///
+    /// [`std::time::Instant`] does not expose its bounds, so the nearest valid
+    /// instant is found by searching with [`checked_sub`]. If `self - dur`
+    /// does not overflow, this is the same as `self - dur`.
+    ///
+    /// Returns `None` if this instant or `dur` is `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easytime::{Duration, Instant};
+    ///
+    /// let now = Instant::now();
+    /// assert!((now - Duration::MAX).is_none());
+    /// let min = now.saturating_sub(Duration::MAX);
+    /// assert!(min < now);
+    /// assert!((min - Duration::from_nanos(1)).is_none());
+    /// ```
+    ///
+    /// [`checked_sub`]: std::time::Instant::checked_sub
+    #[inline]
+    #[must_use]
+    pub fn saturating_sub(&self, dur: Duration) -> Self {
+        Self(pair_and_then(self.0, dur.0, |this, dur| {
+            Some(saturate(this, dur, time::Instant::checked_sub))
+        }))
+    }
+
+    /// Returns the later of `self` and `other`, ignoring `None`.
+    ///
+    /// Unlike [`Ord::max`], which treats `None` as earlier than any other
+    /// instant, this returns the other instant if one of them is `None`, and
+    /// returns `None` only if both are `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easytime::{Duration, Instant};
+    ///
+    /// let now = Instant::now();
+    /// let later = now + Duration::from_secs(1);
+    /// assert_eq!(now.max_some(later), later);
+    /// assert_eq!(Instant::NONE.max_some(now), now);
+    /// ```
+    #[must_use]
+    pub fn max_some(self, other: Self) -> Self {
+        match (self.0, other.0) {
+            (Some(_), Some(_)) => cmp::max(self, other),
+            (Some(_), None) => self,
+            (None, _) => other,
+        }
+    }
+
+    /// Returns the earlier of `self` and `other`, ignoring `None`.
+    ///
+    /// Unlike [`Ord::min`], which treats `None` as earlier than any other
+    /// instant and therefore returns it, this returns the other instant if
+    /// one of them is `None`, and returns `None` only if both are `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easytime::{Duration, Instant};
+    ///
+    /// let now = Instant::now();
+    /// let later = now + Duration::from_secs(1);
+    /// assert_eq!(now.min_some(later), now);
+    /// assert_eq!(Instant::NONE.min_some(later), later);
+    /// ```
+    #[must_use]
+    pub fn min_some(self, other: Self) -> Self {
+        match (self.0, other.0) {
+            (Some(_), Some(_)) => cmp::min(self, other),
+            (Some(_), None) => self,
+            (None, _) => other,
+        }
+    }
+
     /// Returns the amount of time elapsed from another instant to this one,
     /// or zero duration if that instant is later than this one.
     ///
@@ -88,6 +363,88 @@ impl Instant {
         ))
     }
 
+    /// Returns the amount of time elapsed from a [`std::time::Instant`] to
+    /// this one, or zero duration if that instant is later than this one.
+    ///
+    /// This is equivalent to `self.duration_since(Instant::from(earlier))`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time;
+    ///
+    /// use easytime::{Duration, Instant};
+    ///
+    /// let start = time::Instant::now();
+    /// let later = Instant::from(start) + Duration::from_secs(1);
+    /// assert_eq!(later.duration_since_std(start), Duration::from_secs(1));
+    /// assert_eq!(Instant::NONE.duration_since_std(start), Duration::ZERO);
+    /// ```
+    #[must_use]
+    pub fn duration_since_std(&self, earlier: time::Instant) -> Duration {
+        self.duration_since(Self::from(earlier))
+    }
+
+    /// Returns the amount of time elapsed from another instant to this one,
+    /// or an error if that instant is later than this one or either instant
+    /// is `None`.
+    ///
+    /// Unlike [`duration_since`](Self::duration_since), this does not saturate
+    /// to zero, so it can be used to detect the clock anomalies described in
+    /// the [OS-specific behaviors](Self#os-specific-behaviors) section.
+    ///
+    /// If `earlier` is later than `self`, the error's [`kind`] is
+    /// [`TryFromTimeErrorKind::Underflow`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time;
+    ///
+    /// use easytime::Instant;
+    ///
+    /// let now = Instant::now();
+    /// let later = now + time::Duration::from_secs(1);
+    /// assert_eq!(later.try_duration_since(now), Ok(time::Duration::from_secs(1).into()));
+    /// assert!(now.try_duration_since(later).is_err());
+    /// ```
+    ///
+    /// [`kind`]: TryFromTimeError::kind
+    pub fn try_duration_since(&self, earlier: Self) -> Result<Duration, TryFromTimeError> {
+        match (self.0, earlier.0) {
+            (Some(this), Some(earlier)) => this
+                .checked_duration_since(earlier)
+                .map(Duration::from)
+                .ok_or(TryFromTimeError(TryFromTimeErrorKind::Underflow)),
+            _ => Err(TryFromTimeError::new()),
+        }
+    }
+
+    /// Returns the amount of time elapsed from another instant to this one,
+    /// or `None` if that instant is later than this one or either instant is
+    /// `None`.
+    ///
+    /// This is the same as [`std::time::Instant::checked_duration_since`], so
+    /// code using it can be migrated to `easytime` by renaming the method.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time;
+    ///
+    /// use easytime::Instant;
+    ///
+    /// let now = Instant::now();
+    /// let later = now + time::Duration::from_secs(1);
+    /// assert_eq!(later.checked_duration_since_std(now), Some(time::Duration::from_secs(1)));
+    /// assert_eq!(now.checked_duration_since_std(later), None);
+    /// assert_eq!(now.checked_duration_since_std(Instant::NONE), None);
+    /// ```
+    #[must_use]
+    pub fn checked_duration_since_std(&self, earlier: Self) -> Option<time::Duration> {
+        pair_and_then(self.0.as_ref(), earlier.0, time::Instant::checked_duration_since)
+    }
+
     /// Returns the amount of time elapsed since this instant was created.
     ///
     /// # Examples
@@ -131,6 +488,28 @@ impl Instant {
         self.0
     }
 
+    /// Returns a reference to the contained [`std::time::Instant`] or `None`.
+    ///
+    /// Unlike [`into_inner`](Self::into_inner), this borrows the value instead
+    /// of copying it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easytime::Instant;
+    ///
+    /// let now = Instant::now();
+    /// if let Some(i) = now.as_std() {
+    ///     assert!(i.elapsed() < std::time::Duration::from_secs(60));
+    /// }
+    /// assert_eq!(Instant::NONE.as_std(), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn as_std(&self) -> Option<&time::Instant> {
+        self.0.as_ref()
+    }
+
     /// Returns the contained [`std::time::Instant`] or a default.
     ///
     /// `instant.unwrap_or(default)` is equivalent to `instant.into_inner().unwrap_or(default)`.
@@ -147,17 +526,127 @@ impl Instant {
     ///
     /// `instant.unwrap_or_else(default)` is equivalent to `instant.into_inner().unwrap_or_else(default)`.
     #[inline]
+    #[must_use]
     pub fn unwrap_or_else<F>(self, default: F) -> time::Instant
     where
         F: FnOnce() -> time::Instant,
     {
         self.0.unwrap_or_else(default)
     }
+
+    /// Maps the contained [`std::time::Instant`] by applying a function to it,
+    /// or returns `None` if this instant is `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time;
+    ///
+    /// use easytime::Instant;
+    ///
+    /// let now = Instant::now();
+    /// let later = now.map(|i| i + time::Duration::from_secs(1));
+    /// assert!(later > now);
+    /// assert!(Instant::NONE.map(|i| i + time::Duration::from_secs(1)).is_none());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn map<F>(self, f: F) -> Self
+    where
+        F: FnOnce(time::Instant) -> time::Instant,
+    {
+        Self(self.0.map(f))
+    }
+
+    /// Returns `None` if this instant is `None`, otherwise calls `f` with the
+    /// contained [`std::time::Instant`] and returns the result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time;
+    ///
+    /// use easytime::Instant;
+    ///
+    /// let now = Instant::now();
+    /// assert!(now.and_then(|i| i.checked_add(time::Duration::from_secs(1))).is_some());
+    /// assert!(now.and_then(|i| i.checked_add(time::Duration::MAX)).is_none());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn and_then<F>(self, f: F) -> Self
+    where
+        F: FnOnce(time::Instant) -> Option<time::Instant>,
+    {
+        Self(self.0.and_then(f))
+    }
+
+    /// Returns this instant if it is not `None`, otherwise returns `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easytime::Instant;
+    ///
+    /// let now = Instant::now();
+    /// assert_eq!(Instant::NONE.or(now), now);
+    /// assert_eq!(now.or(Instant::NONE), now);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn or(self, other: Self) -> Self {
+        Self(self.0.or(other.0))
+    }
+
+    /// Returns this instant if it is not `None`, otherwise calls `f` and
+    /// returns the result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easytime::Instant;
+    ///
+    /// assert!(Instant::NONE.or_else(Instant::now).is_some());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn or_else<F>(self, f: F) -> Self
+    where
+        F: FnOnce() -> Self,
+    {
+        Self(self.0.or_else(|| f().0))
+    }
 }
 
 // -----------------------------------------------------------------------------
 // Trait implementations
 
+/// Applies `op` to `this` and `dur`, or, if that overflows, to `this` and the
+/// largest duration less than `dur` for which it does not.
+fn saturate(
+    this: time::Instant,
+    dur: time::Duration,
+    op: fn(&time::Instant, time::Duration) -> Option<time::Instant>,
+) -> time::Instant {
+    if let Some(res) = op(&this, dur) {
+        return res;
+    }
+    // `op` succeeds for `lo` and fails for `hi`.
+    let (mut lo, mut hi) = (0, dur.as_nanos());
+    let mut res = this;
+    while hi - lo > 1 {
+        let mid = lo + (hi - lo) / 2;
+        match duration_from_nanos_u128(mid).and_then(|mid| op(&this, mid)) {
+            Some(r) => {
+                lo = mid;
+                res = r;
+            }
+            None => hi = mid,
+        }
+    }
+    res
+}
+
 impl PartialEq<time::Instant> for Instant {
     fn eq(&self, other: &time::Instant) -> bool {
         self.0 == Some(*other)
@@ -182,6 +671,24 @@ impl PartialOrd<Instant> for time::Instant {
     }
 }
 
+/// Returns [`Instant::NONE`].
+///
+/// Unlike `Duration`, there is no meaningful "zero" instant, and using
+/// [`Instant::now`] would make the default value depend on when it was
+/// created. `NONE` is a neutral value that propagates through arithmetic
+/// like any other `None`.
+impl Default for Instant {
+    fn default() -> Self {
+        Self::NONE
+    }
+}
+
+impl AsRef<Option<time::Instant>> for Instant {
+    fn as_ref(&self) -> &Option<time::Instant> {
+        &self.0
+    }
+}
+
 impl From<time::Instant> for Instant {
     fn from(instant: time::Instant) -> Self {
         Self(Some(instant))
@@ -194,11 +701,17 @@ impl From<Option<time::Instant>> for Instant {
     }
 }
 
+impl From<Instant> for Option<time::Instant> {
+    fn from(instant: Instant) -> Self {
+        instant.0
+    }
+}
+
 impl TryFrom<Instant> for time::Instant {
     type Error = TryFromTimeError;
 
     fn try_from(instant: Instant) -> Result<Self, Self::Error> {
-        instant.into_inner().ok_or(TryFromTimeError(()))
+        instant.into_inner().ok_or(TryFromTimeError::new())
     }
 }
 
@@ -270,6 +783,46 @@ impl Sub<time::Instant> for Instant {
     type Output = Duration;
 
     fn sub(self, other: time::Instant) -> Self::Output {
-        self.duration_since(Self::from(other))
+        self.duration_since_std(other)
+    }
+}
+
+impl Add<Duration> for time::Instant {
+    type Output = Instant;
+
+    fn add(self, other: Duration) -> Self::Output {
+        Instant::from(self) + other
+    }
+}
+
+impl Sub<Duration> for time::Instant {
+    type Output = Instant;
+
+    fn sub(self, other: Duration) -> Self::Output {
+        Instant::from(self) - other
+    }
+}
+
+impl Add<Instant> for Duration {
+    type Output = Instant;
+
+    fn add(self, other: Instant) -> Self::Output {
+        other + self
+    }
+}
+
+impl Add<time::Instant> for Duration {
+    type Output = Instant;
+
+    fn add(self, other: time::Instant) -> Self::Output {
+        Instant::from(other) + self
+    }
+}
+
+impl Sub<Instant> for time::Instant {
+    type Output = Duration;
+
+    fn sub(self, other: Instant) -> Self::Output {
+        Instant::from(self).duration_since(other)
     }
 }
@@ -0,0 +1,68 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use crate::{Duration, Instant};
+
+/// A helper to determine when a periodic task should fire.
+///
+/// All arithmetic is performed using `easytime` types, so it never panics.
+/// If the next deadline cannot be computed (e.g., due to overflow), or the
+/// period is zero or `None`, the interval becomes inert and [`tick`] always
+/// returns `false`.
+///
+/// # Examples
+///
+/// ```
+/// use easytime::{Duration, Instant, Interval};
+///
+/// let start = Instant::now();
+/// let mut interval = Interval::new_at(start, Duration::from_secs(1));
+/// assert!(!interval.tick(start));
+/// assert!(interval.tick(start + Duration::from_secs(1)));
+/// assert!(!interval.tick(start + Duration::from_millis(1_500)));
+/// assert!(interval.tick(start + Duration::from_secs(3)));
+/// ```
+///
+/// [`tick`]: Self::tick
+#[derive(Debug, Clone)]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub struct Interval {
+    period: Duration,
+    next: Instant,
+}
+
+impl Interval {
+    /// Creates a new `Interval` that first fires one `period` after now.
+    #[must_use]
+    pub fn new(period: Duration) -> Self {
+        Self::new_at(Instant::now(), period)
+    }
+
+    /// Creates a new `Interval` that first fires one `period` after `start`.
+    #[must_use]
+    pub fn new_at(start: Instant, period: Duration) -> Self {
+        let next = if period.is_zero() { Instant::NONE } else { start + period };
+        Self { period, next }
+    }
+
+    /// Returns `true` if at least one period has elapsed since the last time
+    /// this returned `true` (or since the start).
+    ///
+    /// When this returns `true`, the next deadline is advanced by as many
+    /// whole periods as needed to be later than `now`, so missed ticks are
+    /// not fired in a burst.
+    pub fn tick(&mut self, now: Instant) -> bool {
+        if self.next.is_none() || now.is_none() || now < self.next {
+            return false;
+        }
+        let behind = now - self.next;
+        self.next += behind - behind % self.period + self.period;
+        true
+    }
+
+    /// Returns `true` if this interval is inert, i.e., [`tick`](Self::tick)
+    /// will never return `true` again.
+    #[must_use]
+    pub fn is_inert(&self) -> bool {
+        self.next.is_none()
+    }
+}
@@ -0,0 +1,97 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use crate::{Duration, TryFromTimeError};
+
+mod private {
+    #[allow(unknown_lints, unnameable_types)] // Not public API. unnameable_types is available on Rust 1.79+
+    pub trait Sealed {}
+}
+
+impl<I: Iterator<Item = Duration>> private::Sealed for I {}
+
+/// Extension methods for iterators over [`Duration`]s.
+///
+/// This trait is sealed and cannot be implemented for types outside of `easytime`.
+pub trait DurationIteratorExt: Iterator<Item = Duration> + private::Sealed {
+    /// Sums the durations of the iterator, returning an error instead of `None`.
+    ///
+    /// This short-circuits on the first element that is `None` or whose
+    /// addition overflows. See [`Duration::try_add`] for the error kinds.
+    ///
+    /// An empty iterator returns [`Duration::ZERO`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easytime::{Duration, DurationIteratorExt};
+    ///
+    /// let timeouts = [Duration::from_secs(1), Duration::from_millis(500)];
+    /// assert_eq!(timeouts.iter().copied().try_sum(), Ok(Duration::from_millis(1_500)));
+    ///
+    /// let timeouts = [Duration::MAX, Duration::from_secs(1)];
+    /// assert!(timeouts.iter().copied().try_sum().is_err());
+    /// ```
+    #[inline]
+    fn try_sum(self) -> Result<Duration, TryFromTimeError>
+    where
+        Self: Sized,
+    {
+        let mut total = Duration::ZERO;
+        for dur in self {
+            total = total.try_add(dur)?;
+        }
+        Ok(total)
+    }
+
+    /// Returns the minimum of the durations of the iterator that are not
+    /// `None`.
+    ///
+    /// The derived [`Ord`] for `Duration` orders `None` before any other
+    /// duration, so [`Iterator::min`] returns `None` if any element is `None`.
+    /// This method skips such elements instead.
+    ///
+    /// Returns `None` if the iterator is empty or all elements are `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easytime::{Duration, DurationIteratorExt};
+    ///
+    /// let latencies = [Duration::from_millis(30), Duration::NONE, Duration::from_millis(10)];
+    /// assert_eq!(latencies.iter().copied().min_some(), Some(Duration::from_millis(10)));
+    /// assert_eq!([Duration::NONE].iter().copied().min_some(), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    fn min_some(self) -> Option<Duration>
+    where
+        Self: Sized,
+    {
+        self.filter(Duration::is_some).min()
+    }
+
+    /// Returns the maximum of the durations of the iterator that are not
+    /// `None`.
+    ///
+    /// Returns `None` if the iterator is empty or all elements are `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easytime::{Duration, DurationIteratorExt};
+    ///
+    /// let latencies = [Duration::from_millis(30), Duration::NONE, Duration::from_millis(10)];
+    /// assert_eq!(latencies.iter().copied().max_some(), Some(Duration::from_millis(30)));
+    /// assert_eq!([Duration::NONE].iter().copied().max_some(), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    fn max_some(self) -> Option<Duration>
+    where
+        Self: Sized,
+    {
+        self.filter(Duration::is_some).max()
+    }
+}
+
+impl<I: Iterator<Item = Duration>> DurationIteratorExt for I {}
@@ -53,8 +53,49 @@ fn foo(secs: u64, nanos: u32, instant: Instant) -> Option<Duration> {
 ## Optional features
 
 - **`std`** *(enabled by default)*
-  - Enable to use [`easytime::Instant`].
+  - Enable to use [`easytime::Instant`] and the helpers built on it, such as `Clock`, `Deadline`, `Stopwatch`, and `Interval`.
   - If disabled this feature, `easytime` can be used in `no_std` environments.
+  - This implicitly enables the `alloc` feature.
+
+- **`alloc`**
+  - Enable to use APIs that require allocation, such as `Duration::to_human_string`.
+  - Unlike the `std` feature, this feature can be used in `no_std` environments that provide a global allocator.
+
+- **`mock`**
+  - Provides `Instant::from_base_offset`, which builds deterministic instants relative to a base instant for use in tests.
+  - This implicitly enables the `std` feature.
+
+- **`borsh`**
+  - Implements `BorshSerialize` and `BorshDeserialize` for [`easytime::Duration`] using [borsh](https://github.com/near/borsh-rs).
+
+- **`rkyv`**
+  - Implements `Archive`, `Serialize`, and `Deserialize` for [`easytime::Duration`] using [rkyv](https://github.com/rkyv/rkyv).
+
+- **`rand`**
+  - Provides `DurationRange`, which implements [rand](https://github.com/rust-random/rand)'s `Distribution<Duration>`.
+
+- **`serde`**
+  - Provides the `easytime::serde` module, which contains helpers for use with [serde](https://github.com/serde-rs/serde)'s `#[serde(with = "...")]` attribute.
+
+- **`serde-compact`**
+  - Provides `easytime::serde::duration_nanos`, which (de)serializes `easytime::Duration` as a single integer of nanoseconds.
+  - This implicitly enables the `serde` feature.
+
+- **`tokio`**
+  - Provides the `easytime::tokio` module, which contains `sleep` and `timeout` functions that take `easytime::Duration` using [tokio](https://tokio.rs)'s timers.
+
+- **`approx`**
+  - Implements [approx](https://github.com/brendanzab/approx)'s `AbsDiffEq` for [`easytime::Duration`].
+
+- **`float-cmp`**
+  - Implements [float-cmp](https://github.com/mikedilger/float-cmp)'s `ApproxEq` for [`easytime::Duration`], comparing the durations as seconds in `f64`.
+
+- **`num-rational`**
+  - Provides `Duration::mul_ratio`, which multiplies a duration by [num-rational](https://github.com/rust-num/num-rational)'s `Ratio<u64>` exactly.
+
+- **`postgres`**
+  - Implements `ToSql` and `FromSql` for [`easytime::Duration`] using [postgres-types](https://github.com/sfackler/rust-postgres), mapping it to the Postgres `interval` type. A `None` duration maps to `NULL`.
+  - This implicitly enables the `std` feature.
 
 <!-- tidy:crate-doc:end -->
 */
@@ -77,12 +118,14 @@ fn foo(secs: u64, nanos: u32, instant: Instant) -> Option<Duration> {
     clippy::exhaustive_structs,
     clippy::impl_trait_in_params,
     // clippy::missing_inline_in_public_items,
-    // clippy::std_instead_of_alloc,
+    clippy::std_instead_of_alloc,
     clippy::std_instead_of_core,
 )]
 // docs.rs only (cfg is enabled by docs.rs, not build script)
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
 #[cfg(doc)]
 extern crate self as easytime;
 #[cfg(feature = "std")]
@@ -97,12 +140,126 @@ mod assert_impl;
 mod utils;
 
 mod duration;
-pub use crate::duration::Duration;
+pub use crate::duration::{DisplayInner, Duration, DurationUnit, RoundMode};
+
+mod macros;
+
+// Not public API.
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::macros::almost_eq;
+}
+
+#[cfg(feature = "std")]
+mod clock;
+#[cfg(feature = "std")]
+pub use crate::clock::{Clock, SystemClock};
 
 #[cfg(feature = "std")]
 mod instant;
 #[cfg(feature = "std")]
 pub use crate::instant::Instant;
 
+mod raw_instant;
+pub use crate::raw_instant::RawInstant;
+
+#[cfg(feature = "std")]
+mod deadline;
+#[cfg(feature = "std")]
+pub use crate::deadline::Deadline;
+#[cfg(feature = "std")]
+mod interval;
+#[cfg(feature = "std")]
+pub use crate::interval::Interval;
+#[cfg(feature = "std")]
+mod stopwatch;
+#[cfg(feature = "std")]
+pub use crate::stopwatch::Stopwatch;
+#[cfg(feature = "std")]
+mod rate;
+#[cfg(feature = "std")]
+pub use crate::rate::RateCounter;
+
 mod error;
-pub use crate::error::TryFromTimeError;
+pub use crate::error::{TryFromTimeError, TryFromTimeErrorKind};
+
+mod iter;
+pub use crate::iter::DurationIteratorExt;
+
+mod policy;
+pub use crate::policy::{DurationOps, OverflowPolicy};
+
+mod slice;
+pub use crate::slice::DurationSliceExt;
+
+mod ticker;
+pub use crate::ticker::Ticker;
+
+mod backoff;
+pub use crate::backoff::Backoff;
+
+#[cfg(feature = "rand")]
+mod rand;
+#[cfg(feature = "rand")]
+pub use crate::rand::DurationRange;
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub mod serde;
+#[cfg(feature = "tokio")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
+pub mod tokio;
+
+#[cfg(feature = "approx")]
+mod approx;
+#[cfg(feature = "borsh")]
+mod borsh;
+#[cfg(feature = "float-cmp")]
+mod float_cmp;
+#[cfg(feature = "num-rational")]
+mod num_rational;
+#[cfg(feature = "postgres")]
+mod postgres;
+#[cfg(feature = "rkyv")]
+mod rkyv;
+
+// Discarding the result of checked arithmetic drops the only signal of
+// overflow, so make sure the `unused_must_use` lint catches it.
+#[cfg(doctest)]
+mod must_use {
+    //! ```compile_fail
+    //! #![deny(unused_must_use)]
+    //! use easytime::Duration;
+    //! Duration::MAX + Duration::from_secs(1);
+    //! ```
+    //!
+    //! ```compile_fail
+    //! #![deny(unused_must_use)]
+    //! use easytime::Duration;
+    //! Duration::from_secs(1) * 2;
+    //! ```
+    //!
+    //! ```compile_fail
+    //! #![deny(unused_must_use)]
+    //! use easytime::Duration;
+    //! Duration::from_secs(1).checked_mul_pow2(64);
+    //! ```
+    //!
+    //! ```compile_fail
+    //! #![deny(unused_must_use)]
+    //! use easytime::Duration;
+    //! Duration::NONE.unwrap_or_else(Default::default);
+    //! ```
+    //!
+    //! ```compile_fail
+    //! #![deny(unused_must_use)]
+    //! use easytime::{Duration, Instant};
+    //! Instant::now() + Duration::MAX;
+    //! ```
+    //!
+    //! ```compile_fail
+    //! #![deny(unused_must_use)]
+    //! use easytime::Instant;
+    //! Instant::now() - Instant::now();
+    //! ```
+}
@@ -0,0 +1,78 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::ops::Sub;
+
+use crate::Duration;
+
+/// Asserts that two time values are within `tolerance` of each other.
+///
+/// The values can be of any `Copy + PartialOrd` type whose subtraction yields
+/// a type convertible into [`Duration`](crate::Duration), such as
+/// [`Duration`](crate::Duration), `Instant`,
+/// [`std::time::Duration`], and [`std::time::Instant`]. The tolerance can be
+/// either `easytime::Duration` or `std::time::Duration`.
+///
+/// The assertion fails if the difference or the tolerance is `None`, so
+/// `Duration::NONE` and `Instant::NONE` are never almost equal to anything.
+///
+/// Like [`assert_eq!`], this macro takes an optional custom panic message.
+///
+/// # Examples
+///
+/// ```
+/// use easytime::{assert_duration_almost_eq, Duration, Instant};
+///
+/// assert_duration_almost_eq!(
+///     Duration::from_nanos(1_500),
+///     Duration::from_nanos(1_000),
+///     Duration::from_micros(1),
+/// );
+///
+/// let now = Instant::now();
+/// let later = now + Duration::from_nanos(10);
+/// assert_duration_almost_eq!(now, later, Duration::from_micros(1), "clock drifted");
+/// ```
+#[macro_export]
+macro_rules! assert_duration_almost_eq {
+    ($left:expr, $right:expr, $tolerance:expr $(,)?) => {
+        match (&$left, &$right, $crate::Duration::from($tolerance)) {
+            (left, right, tolerance) => {
+                if !$crate::__private::almost_eq(*left, *right, tolerance) {
+                    ::core::panic!(
+                        "assertion failed: `left` is almost equal to `right`\n     left: {:?}\n    right: {:?}\ntolerance: {:?}",
+                        left,
+                        right,
+                        tolerance,
+                    );
+                }
+            }
+        }
+    };
+    ($left:expr, $right:expr, $tolerance:expr, $($arg:tt)+) => {
+        match (&$left, &$right, $crate::Duration::from($tolerance)) {
+            (left, right, tolerance) => {
+                if !$crate::__private::almost_eq(*left, *right, tolerance) {
+                    ::core::panic!(
+                        "assertion failed: `left` is almost equal to `right`\n     left: {:?}\n    right: {:?}\ntolerance: {:?}: {}",
+                        left,
+                        right,
+                        tolerance,
+                        ::core::format_args!($($arg)+),
+                    );
+                }
+            }
+        }
+    };
+}
+
+// Not public API. Used by `assert_duration_almost_eq!`.
+#[doc(hidden)]
+#[must_use]
+pub fn almost_eq<T, D>(left: T, right: T, tolerance: Duration) -> bool
+where
+    T: PartialOrd + Sub<Output = D>,
+    D: Into<Duration>,
+{
+    let diff = if left >= right { left - right } else { right - left };
+    diff.into().approx_eq(Duration::ZERO, tolerance)
+}
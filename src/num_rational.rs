@@ -0,0 +1,35 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use ::num_rational::Ratio;
+
+use crate::Duration;
+
+impl Duration {
+    /// Multiplies this `Duration` by an exact rational number.
+    ///
+    /// This is the same as [`mul_div`](Self::mul_div) with the numerator and
+    /// denominator of `ratio`: the result is computed with 128-bit nanosecond
+    /// arithmetic and rounded down to the nearest nanosecond.
+    ///
+    /// Returns [`Duration::NONE`] if `self` is `None`, the denominator of
+    /// `ratio` is zero (as created by [`Ratio::new_raw`]), or the result
+    /// overflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easytime::Duration;
+    /// use num_rational::Ratio;
+    ///
+    /// // Converts 44.1 kHz samples to 48 kHz samples.
+    /// let dur = Duration::from_secs(3);
+    /// assert_eq!(dur.mul_ratio(Ratio::new(48_000, 44_100)), Duration::new(3, 265_306_122));
+    /// assert!(dur.mul_ratio(Ratio::new_raw(1, 0)).is_none());
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "num-rational")))]
+    #[inline]
+    #[must_use]
+    pub const fn mul_ratio(self, ratio: Ratio<u64>) -> Duration {
+        self.mul_div(*ratio.numer(), *ratio.denom())
+    }
+}
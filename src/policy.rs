@@ -0,0 +1,158 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::{
+    ops::{Add, Mul, Sub},
+    time,
+};
+
+use crate::Duration;
+
+/// What to do when arithmetic on a [`DurationOps`] overflows.
+///
+/// Operands that are already `None` always produce `None`, regardless of the
+/// policy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum OverflowPolicy {
+    /// Returns [`Duration::NONE`], like the arithmetic on [`Duration`].
+    None,
+    /// Clamps the result to [`Duration::ZERO`] or [`Duration::MAX`].
+    Saturate,
+    /// Panics if debug assertions are enabled, and otherwise returns
+    /// [`Duration::NONE`] like [`OverflowPolicy::None`].
+    ///
+    /// Like the overflow checks of integer arithmetic, this catches overflow
+    /// during development without adding panics to release builds.
+    Panic,
+}
+
+/// A [`Duration`] whose arithmetic follows an [`OverflowPolicy`].
+///
+/// This is created by [`Duration::with_policy`]. The result of arithmetic
+/// keeps the policy of the left-hand operand, so the policy only needs to be
+/// chosen once.
+///
+/// # Examples
+///
+/// ```
+/// use easytime::{Duration, OverflowPolicy};
+///
+/// let dur = Duration::from_secs(1).with_policy(OverflowPolicy::Saturate);
+/// assert_eq!((dur - Duration::from_secs(2)).get(), Duration::ZERO);
+/// assert_eq!((dur * u32::MAX + Duration::MAX).get(), Duration::MAX);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DurationOps {
+    value: Duration,
+    policy: OverflowPolicy,
+}
+
+impl Duration {
+    /// Wraps this `Duration` so that subsequent arithmetic follows `policy`.
+    ///
+    /// See [`DurationOps`] for details.
+    #[inline]
+    #[must_use]
+    pub const fn with_policy(self, policy: OverflowPolicy) -> DurationOps {
+        DurationOps { value: self, policy }
+    }
+}
+
+impl DurationOps {
+    /// Returns the wrapped `Duration`.
+    #[inline]
+    #[must_use]
+    pub const fn get(self) -> Duration {
+        self.value
+    }
+
+    /// Returns the overflow policy of this value.
+    #[inline]
+    #[must_use]
+    pub const fn policy(self) -> OverflowPolicy {
+        self.policy
+    }
+
+    fn apply<T>(
+        self,
+        rhs: Option<T>,
+        op: fn(time::Duration, T) -> Option<time::Duration>,
+        saturated: Duration,
+        msg: &str,
+    ) -> Self {
+        let value = match (self.value.0, rhs) {
+            (Some(lhs), Some(rhs)) => match op(lhs, rhs) {
+                Some(res) => Duration(Some(res)),
+                None => match self.policy {
+                    OverflowPolicy::None => Duration::NONE,
+                    OverflowPolicy::Saturate => saturated,
+                    OverflowPolicy::Panic => {
+                        if cfg!(debug_assertions) {
+                            panic!("{msg}");
+                        }
+                        Duration::NONE
+                    }
+                },
+            },
+            _ => Duration::NONE,
+        };
+        value.with_policy(self.policy)
+    }
+}
+
+impl From<DurationOps> for Duration {
+    fn from(ops: DurationOps) -> Self {
+        ops.value
+    }
+}
+
+impl Add<Duration> for DurationOps {
+    type Output = Self;
+
+    /// # Panics
+    ///
+    /// Panics if the addition overflows, the policy is
+    /// [`OverflowPolicy::Panic`], and debug assertions are enabled.
+    fn add(self, rhs: Duration) -> Self::Output {
+        self.apply(
+            rhs.0,
+            time::Duration::checked_add,
+            Duration::MAX,
+            "overflow when adding durations",
+        )
+    }
+}
+
+impl Sub<Duration> for DurationOps {
+    type Output = Self;
+
+    /// # Panics
+    ///
+    /// Panics if the subtraction overflows, the policy is
+    /// [`OverflowPolicy::Panic`], and debug assertions are enabled.
+    fn sub(self, rhs: Duration) -> Self::Output {
+        self.apply(
+            rhs.0,
+            time::Duration::checked_sub,
+            Duration::ZERO,
+            "overflow when subtracting durations",
+        )
+    }
+}
+
+impl Mul<u32> for DurationOps {
+    type Output = Self;
+
+    /// # Panics
+    ///
+    /// Panics if the multiplication overflows, the policy is
+    /// [`OverflowPolicy::Panic`], and debug assertions are enabled.
+    fn mul(self, rhs: u32) -> Self::Output {
+        self.apply(
+            Some(rhs),
+            time::Duration::checked_mul,
+            Duration::MAX,
+            "overflow when multiplying duration by scalar",
+        )
+    }
+}
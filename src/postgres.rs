@@ -0,0 +1,71 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use alloc::boxed::Box;
+use std::error::Error;
+
+use ::bytes::{BufMut as _, BytesMut};
+use ::postgres_types::{to_sql_checked, FromSql, IsNull, ToSql, Type};
+
+use crate::Duration;
+
+const MICROS_PER_DAY: i128 = 24 * 60 * 60 * 1_000_000;
+
+/// Encodes `Duration` as a Postgres `interval`.
+///
+/// The interval is stored as microseconds only (with zero days and months),
+/// so sub-microsecond precision is truncated. `None` is encoded as `NULL`.
+///
+/// Returns an error if the duration does not fit in an `interval`, i.e., it
+/// is longer than `i64::MAX` microseconds (about 292,000 years).
+#[cfg_attr(docsrs, doc(cfg(feature = "postgres")))]
+impl ToSql for Duration {
+    fn to_sql(&self, _: &Type, out: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+        let micros = match self.as_micros() {
+            Some(micros) => i64::try_from(micros)
+                .map_err(|_| "duration is too large to be encoded as an interval")?,
+            None => return Ok(IsNull::Yes),
+        };
+        out.put_i64(micros);
+        out.put_i32(0); // days
+        out.put_i32(0); // months
+        Ok(IsNull::No)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        *ty == Type::INTERVAL
+    }
+
+    to_sql_checked!();
+}
+
+/// Decodes `Duration` from a Postgres `interval`. `NULL` is decoded as `None`.
+///
+/// Days are converted as 24 hours. Returns an error if the interval has a
+/// month component (months have no fixed length) or is negative.
+#[cfg_attr(docsrs, doc(cfg(feature = "postgres")))]
+impl<'a> FromSql<'a> for Duration {
+    fn from_sql(_: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        if raw.len() != 16 {
+            return Err("invalid message length: interval size mismatch".into());
+        }
+        let micros = i64::from_be_bytes(raw[..8].try_into().unwrap());
+        let days = i32::from_be_bytes(raw[8..12].try_into().unwrap());
+        let months = i32::from_be_bytes(raw[12..].try_into().unwrap());
+        if months != 0 {
+            return Err("interval with months cannot be decoded as a duration".into());
+        }
+        let micros = i128::from(days) * MICROS_PER_DAY + i128::from(micros);
+        let micros = u128::try_from(micros)
+            .map_err(|_| "negative interval cannot be decoded as a duration")?;
+        // `micros` is less than 2^68, so this cannot overflow.
+        Ok(Self::from_nanos_u128(micros * 1_000))
+    }
+
+    fn from_sql_null(_: &Type) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        Ok(Self::NONE)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        *ty == Type::INTERVAL
+    }
+}
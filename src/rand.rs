@@ -0,0 +1,58 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use ::rand::{
+    distributions::{Distribution, Uniform},
+    Rng,
+};
+
+use crate::{duration::duration_from_nanos_u128, Duration};
+
+/// A distribution to sample `Duration`s uniformly from a closed range.
+///
+/// # Examples
+///
+/// ```
+/// use easytime::{Duration, DurationRange};
+/// use rand::distributions::Distribution as _;
+///
+/// let range = DurationRange::new(Duration::from_millis(10), Duration::from_millis(20)).unwrap();
+/// let d = range.sample(&mut rand::thread_rng());
+/// assert!(Duration::from_millis(10) <= d && d <= Duration::from_millis(20));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+pub struct DurationRange {
+    nanos: Uniform<u128>,
+}
+
+impl DurationRange {
+    /// Creates a new `DurationRange` that samples durations in `min..=max`.
+    ///
+    /// Returns `None` if `min` or `max` is `None`, or if `min` is greater than
+    /// `max`. Therefore, all sampled durations are `Some`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easytime::{Duration, DurationRange};
+    ///
+    /// assert!(DurationRange::new(Duration::ZERO, Duration::MAX).is_some());
+    /// assert!(DurationRange::new(Duration::from_secs(2), Duration::from_secs(1)).is_none());
+    /// assert!(DurationRange::new(Duration::NONE, Duration::from_secs(1)).is_none());
+    /// ```
+    #[must_use]
+    pub fn new(min: Duration, max: Duration) -> Option<Self> {
+        match (min.as_nanos(), max.as_nanos()) {
+            (Some(min), Some(max)) if min <= max => {
+                Some(Self { nanos: Uniform::new_inclusive(min, max) })
+            }
+            _ => None,
+        }
+    }
+}
+
+impl Distribution<Duration> for DurationRange {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Duration {
+        Duration(duration_from_nanos_u128(self.nanos.sample(rng)))
+    }
+}
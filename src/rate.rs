@@ -0,0 +1,90 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use alloc::collections::VecDeque;
+
+use crate::{Duration, Instant};
+
+/// Counts events over a moving window of time, built on [`Instant`].
+///
+/// Events are recorded with the instant at which they occurred, and
+/// [`rate_per_sec`](Self::rate_per_sec) reports how many of them fall within
+/// a trailing window. Events older than the retention period are pruned
+/// lazily when a new event is recorded.
+///
+/// Events are expected to be recorded in chronological order, as is the
+/// case for instants obtained from [`Instant::now`].
+///
+/// All arithmetic is performed using `easytime` types, so it never panics.
+///
+/// # Examples
+///
+/// ```
+/// use easytime::{Duration, Instant, RateCounter};
+///
+/// let start = Instant::now();
+/// let mut counter = RateCounter::new(Duration::from_secs(10));
+/// for i in 0..10 {
+///     counter.record(start + Duration::from_millis(i * 100));
+/// }
+/// let now = start + Duration::from_secs(1);
+/// assert_eq!(counter.rate_per_sec(Duration::from_secs(2), now), Some(5.0));
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub struct RateCounter {
+    events: VecDeque<Instant>,
+    retention: Duration,
+}
+
+impl RateCounter {
+    /// Creates a new `RateCounter` that keeps events for `retention`.
+    ///
+    /// `retention` should be at least as long as the longest window passed
+    /// to [`rate_per_sec`](Self::rate_per_sec); events that have already been
+    /// pruned are not counted. If `retention` is `None`, no events are pruned.
+    #[must_use]
+    pub const fn new(retention: Duration) -> Self {
+        Self { events: VecDeque::new(), retention }
+    }
+
+    /// Returns the retention period of this counter.
+    #[must_use]
+    pub const fn retention(&self) -> Duration {
+        self.retention
+    }
+
+    /// Records an event that occurred at `at`, and prunes events that are
+    /// older than the retention period relative to `at`.
+    ///
+    /// `Instant::NONE` is ignored.
+    pub fn record(&mut self, at: Instant) {
+        if at.is_none() {
+            return;
+        }
+        if self.retention.is_some() {
+            while let Some(&oldest) = self.events.front() {
+                if at.duration_since(oldest) <= self.retention {
+                    break;
+                }
+                self.events.pop_front();
+            }
+        }
+        self.events.push_back(at);
+    }
+
+    /// Returns the number of events per second within the trailing `window`
+    /// ending at `now`, i.e., the events that occurred in `(now - window, now]`.
+    ///
+    /// Returns `None` if `now` or `window` is `None`, or `window` is zero.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)] // The count is far below 2^52 in practice.
+    pub fn rate_per_sec(&self, window: Duration, now: Instant) -> Option<f64> {
+        if now.is_none() || window.is_zero() {
+            return None;
+        }
+        let secs = window.as_secs_f64()?;
+        let count =
+            self.events.iter().filter(|&&at| at <= now && now.duration_since(at) < window).count();
+        Some(count as f64 / secs)
+    }
+}
@@ -0,0 +1,128 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use crate::{
+    duration::{duration_from_nanos_u128, NANOS_PER_SEC},
+    Duration,
+};
+
+/// A measurement of a monotonic tick counter, for use where
+/// [`std::time::Instant`] is not available.
+///
+/// A `RawInstant` is a tick count paired with the frequency of the counter it
+/// was read from, in hertz. This type does not read any clock itself; it is
+/// intended for `no_std` environments (e.g., embedded targets with a hardware
+/// timer) where the user supplies the tick count.
+///
+/// All arithmetic is panic-free: operations on instants with different
+/// frequencies or with a frequency of zero return [`Duration::NONE`] or
+/// `None`.
+///
+/// # Examples
+///
+/// ```
+/// use easytime::{Duration, RawInstant};
+///
+/// // A 32.768 kHz real-time clock.
+/// let start = RawInstant::from_ticks(1_000, 32_768);
+/// let end = RawInstant::from_ticks(1_000 + 3 * 32_768, 32_768);
+/// assert_eq!(end.duration_since(start), Duration::from_secs(3));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RawInstant {
+    ticks: u64,
+    hz: u32,
+}
+
+impl RawInstant {
+    /// Creates a new `RawInstant` from a tick count of a counter running at
+    /// `hz` ticks per second.
+    #[inline]
+    #[must_use]
+    pub const fn from_ticks(ticks: u64, hz: u32) -> Self {
+        Self { ticks, hz }
+    }
+
+    /// Returns the tick count of this instant.
+    #[inline]
+    #[must_use]
+    pub const fn ticks(&self) -> u64 {
+        self.ticks
+    }
+
+    /// Returns the frequency of the counter this instant was read from, in
+    /// hertz.
+    #[inline]
+    #[must_use]
+    pub const fn hz(&self) -> u32 {
+        self.hz
+    }
+
+    /// Returns the amount of time elapsed from another instant to this one,
+    /// or zero duration if that instant is later than this one.
+    ///
+    /// Sub-nanosecond remainders are truncated.
+    ///
+    /// Returns [`Duration::NONE`] if the two instants have different
+    /// frequencies, or if the frequency is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easytime::{Duration, RawInstant};
+    ///
+    /// let a = RawInstant::from_ticks(0, 1_000);
+    /// let b = RawInstant::from_ticks(1_500, 1_000);
+    /// assert_eq!(b.duration_since(a), Duration::from_millis(1_500));
+    /// assert_eq!(a.duration_since(b), Duration::ZERO);
+    /// assert!(b.duration_since(RawInstant::from_ticks(0, 32_768)).is_none());
+    /// ```
+    #[must_use]
+    pub const fn duration_since(&self, earlier: Self) -> Duration {
+        if self.hz != earlier.hz {
+            return Duration::NONE;
+        }
+        ticks_to_duration(self.ticks.saturating_sub(earlier.ticks), self.hz)
+    }
+
+    /// Returns `Some(t)` where `t` is the time `self + duration` if `t` can be
+    /// represented as `RawInstant` (which means it's inside the bounds of the
+    /// underlying tick counter), `None` otherwise.
+    ///
+    /// `duration` is rounded down to a whole number of ticks. Returns `None`
+    /// if `duration` is `None` or the frequency is zero.
+    #[must_use]
+    pub fn checked_add(&self, duration: Duration) -> Option<Self> {
+        let ticks = self.ticks.checked_add(duration_to_ticks(duration, self.hz)?)?;
+        Some(Self::from_ticks(ticks, self.hz))
+    }
+
+    /// Returns `Some(t)` where `t` is the time `self - duration` if `t` can be
+    /// represented as `RawInstant` (which means it's inside the bounds of the
+    /// underlying tick counter), `None` otherwise.
+    ///
+    /// `duration` is rounded down to a whole number of ticks. Returns `None`
+    /// if `duration` is `None` or the frequency is zero.
+    #[must_use]
+    pub fn checked_sub(&self, duration: Duration) -> Option<Self> {
+        let ticks = self.ticks.checked_sub(duration_to_ticks(duration, self.hz)?)?;
+        Some(Self::from_ticks(ticks, self.hz))
+    }
+}
+
+const fn ticks_to_duration(ticks: u64, hz: u32) -> Duration {
+    if hz == 0 {
+        return Duration::NONE;
+    }
+    // `ticks * 10^9 / hz` is at most `u64::MAX * 10^9`, which fits in both
+    // `u128` and `core::time::Duration`.
+    Duration(duration_from_nanos_u128(ticks as u128 * NANOS_PER_SEC as u128 / hz as u128))
+}
+
+fn duration_to_ticks(duration: Duration, hz: u32) -> Option<u64> {
+    if hz == 0 {
+        return None;
+    }
+    // `duration` is less than 2^94 nanoseconds, so this cannot overflow.
+    let ticks = duration.as_nanos()? * u128::from(hz) / u128::from(NANOS_PER_SEC);
+    u64::try_from(ticks).ok()
+}
@@ -0,0 +1,41 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::time;
+
+use ::rkyv::{
+    option::ArchivedOption, rancor::Fallible, time::ArchivedDuration, Archive, Deserialize, Place,
+    Serialize,
+};
+
+use crate::Duration;
+
+/// `Duration` is archived in the same way as `Option<std::time::Duration>`,
+/// that is, as an [`ArchivedOption`] of [`ArchivedDuration`].
+///
+/// This is a fixed-size layout consisting of a one-byte tag followed (when
+/// the tag is `Some`) by the seconds as a little-endian `u64` and the
+/// sub-second nanoseconds as a little-endian `u32`, so the archived value can
+/// be accessed without deserialization.
+#[cfg_attr(docsrs, doc(cfg(feature = "rkyv")))]
+impl Archive for Duration {
+    type Archived = ArchivedOption<ArchivedDuration>;
+    type Resolver = <Option<time::Duration> as Archive>::Resolver;
+
+    fn resolve(&self, resolver: Self::Resolver, out: Place<Self::Archived>) {
+        self.0.resolve(resolver, out);
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "rkyv")))]
+impl<S: Fallible + ?Sized> Serialize<S> for Duration {
+    fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "rkyv")))]
+impl<D: Fallible + ?Sized> Deserialize<Duration, D> for ArchivedOption<ArchivedDuration> {
+    fn deserialize(&self, deserializer: &mut D) -> Result<Duration, D::Error> {
+        Deserialize::<Option<time::Duration>, D>::deserialize(self, deserializer).map(Duration)
+    }
+}
@@ -0,0 +1,379 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Helpers for use with serde's `#[serde(with = "...")]` attribute.
+
+/// (De)serializes an [`Instant`](crate::Instant) as the amount of time
+/// elapsed since a reference instant.
+///
+/// [`std::time::Instant`] is opaque, so it cannot be serialized as is.
+/// This module instead encodes it as an `Option<std::time::Duration>`
+/// measured from the reference instant set by [`with_reference`], and
+/// decodes it by adding that duration back to the reference instant.
+/// `Instant::NONE` is encoded as `None`.
+///
+/// The reference instant is per-thread and only set for the duration of the
+/// closure passed to [`with_reference`]. (De)serializing a `Some` instant
+/// outside of it returns an error.
+///
+/// # Caveats
+///
+/// Instants are only meaningful within the process that created them. The
+/// encoded value is relative to the reference instant, so deserializing it
+/// in another process (or with a different reference instant) yields an
+/// instant that has no relation to the original one.
+///
+/// Serializing an instant earlier than the reference instant returns an
+/// error, because durations cannot be negative.
+///
+/// # Examples
+///
+/// ```
+/// use easytime::{serde::instant_relative, Duration, Instant};
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Task {
+///     #[serde(with = "instant_relative")]
+///     deadline: Instant,
+/// }
+///
+/// let start = Instant::now();
+/// let task = Task { deadline: start + Duration::from_secs(5) };
+/// let json = instant_relative::with_reference(start, || serde_json::to_string(&task)).unwrap();
+/// assert_eq!(json, r#"{"deadline":{"secs":5,"nanos":0}}"#);
+///
+/// let task: Task =
+///     instant_relative::with_reference(start, || serde_json::from_str(&json)).unwrap();
+/// assert_eq!(task.deadline, start + Duration::from_secs(5));
+/// ```
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub mod instant_relative {
+    use core::cell::Cell;
+    use std::time;
+
+    use ::serde::{
+        de::Error as _, ser::Error as _, Deserialize as _, Deserializer, Serialize as _, Serializer,
+    };
+
+    use crate::Instant;
+
+    pub(super) const NO_REFERENCE: &str =
+        "no reference instant is set; use `instant_relative::with_reference`";
+
+    std::thread_local! {
+        static REFERENCE: Cell<Option<time::Instant>> = Cell::new(None);
+    }
+
+    /// Calls `f` with `reference` set as the reference instant of the current
+    /// thread, and returns the result.
+    ///
+    /// The previous reference instant is restored when this function returns,
+    /// even if `f` panics, so calls can be nested.
+    ///
+    /// If `reference` is `Instant::NONE`, no reference instant is set within `f`.
+    pub fn with_reference<F, R>(reference: Instant, f: F) -> R
+    where
+        F: FnOnce() -> R,
+    {
+        struct Restore(Option<time::Instant>);
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                REFERENCE.with(|r| r.set(self.0));
+            }
+        }
+
+        let _restore = Restore(REFERENCE.with(|r| r.replace(reference.into_inner())));
+        f()
+    }
+
+    pub(super) fn reference() -> Option<time::Instant> {
+        REFERENCE.with(Cell::get)
+    }
+
+    /// Serializes an `Instant` as the `Duration` since the reference instant.
+    pub fn serialize<S>(instant: &Instant, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let offset = match instant.into_inner() {
+            Some(instant) => {
+                let reference = reference().ok_or_else(|| S::Error::custom(NO_REFERENCE))?;
+                let offset = instant.checked_duration_since(reference).ok_or_else(|| {
+                    S::Error::custom("instant is earlier than the reference instant")
+                })?;
+                Some(offset)
+            }
+            None => None,
+        };
+        offset.serialize(serializer)
+    }
+
+    /// Deserializes an `Instant` by adding the `Duration` to the reference instant.
+    ///
+    /// Returns `Instant::NONE` if the addition overflows.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Instant, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match Option::<time::Duration>::deserialize(deserializer)? {
+            Some(offset) => {
+                let reference = reference().ok_or_else(|| D::Error::custom(NO_REFERENCE))?;
+                Ok(Instant::from(reference) + offset)
+            }
+            None => Ok(Instant::NONE),
+        }
+    }
+}
+
+/// (De)serializes an [`Instant`](crate::Instant) like [`instant_relative`],
+/// but rejects values that were serialized by a different process.
+///
+/// Instants are only meaningful within the process that created them, so
+/// deserializing a persisted instant in a later run silently yields an
+/// unrelated instant with [`instant_relative`]. To guard against this, this
+/// module encodes an instant as an `Option<(u64, std::time::Duration)>` of a
+/// process-unique nonce and the amount of time elapsed since the reference
+/// instant set by [`instant_relative::with_reference`].
+///
+/// When deserializing, a value whose nonce does not match the nonce of the
+/// current process is decoded as `Instant::NONE` rather than an error, so a
+/// stale instant propagates like any other `None` value. The nonce is
+/// randomly generated once per process, so it is not a security measure.
+///
+/// Otherwise, this behaves the same as [`instant_relative`], including its
+/// errors.
+///
+/// # Examples
+///
+/// ```
+/// use easytime::{
+///     serde::{instant_guarded, instant_relative},
+///     Duration, Instant,
+/// };
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Task {
+///     #[serde(with = "instant_guarded")]
+///     deadline: Instant,
+/// }
+///
+/// let start = Instant::now();
+/// let task = Task { deadline: start + Duration::from_secs(5) };
+/// let json = instant_relative::with_reference(start, || serde_json::to_string(&task)).unwrap();
+/// let task: Task =
+///     instant_relative::with_reference(start, || serde_json::from_str(&json)).unwrap();
+/// assert_eq!(task.deadline, start + Duration::from_secs(5));
+/// ```
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub mod instant_guarded {
+    use core::{
+        hash::{BuildHasher as _, Hasher as _},
+        sync::atomic::{AtomicUsize, Ordering},
+    };
+    use std::{collections::hash_map::RandomState, process, time};
+
+    use ::serde::{
+        de::Error as _, ser::Error as _, Deserialize as _, Deserializer, Serialize as _, Serializer,
+    };
+
+    use super::instant_relative::{reference, NO_REFERENCE};
+    use crate::Instant;
+
+    /// Returns the nonce of the current process, generating it on first use.
+    ///
+    /// `AtomicUsize` is used instead of `AtomicU64` because some targets lack
+    /// 64-bit atomics.
+    fn nonce() -> u64 {
+        static NONCE: AtomicUsize = AtomicUsize::new(0);
+
+        match NONCE.load(Ordering::Relaxed) {
+            0 => {}
+            nonce => return nonce as u64,
+        }
+        // `RandomState` is seeded randomly, so this differs between processes
+        // even if the process ID is reused.
+        let mut hasher = RandomState::new().build_hasher();
+        hasher.write_u32(process::id());
+        #[allow(clippy::cast_possible_truncation)] // Truncation is fine for a nonce.
+        let new = (hasher.finish() as usize).max(1);
+        match NONCE.compare_exchange(0, new, Ordering::Relaxed, Ordering::Relaxed) {
+            Ok(_) => new as u64,
+            Err(nonce) => nonce as u64,
+        }
+    }
+
+    /// Serializes an `Instant` as the nonce of the current process and the
+    /// `Duration` since the reference instant.
+    pub fn serialize<S>(instant: &Instant, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let value = match instant.into_inner() {
+            Some(instant) => {
+                let reference = reference().ok_or_else(|| S::Error::custom(NO_REFERENCE))?;
+                let offset = instant.checked_duration_since(reference).ok_or_else(|| {
+                    S::Error::custom("instant is earlier than the reference instant")
+                })?;
+                Some((nonce(), offset))
+            }
+            None => None,
+        };
+        value.serialize(serializer)
+    }
+
+    /// Deserializes an `Instant` by adding the `Duration` to the reference
+    /// instant.
+    ///
+    /// Returns `Instant::NONE` if the value was serialized by a different
+    /// process or the addition overflows.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Instant, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match Option::<(u64, time::Duration)>::deserialize(deserializer)? {
+            Some((other, _)) if other != nonce() => Ok(Instant::NONE),
+            Some((_, offset)) => {
+                let reference = reference().ok_or_else(|| D::Error::custom(NO_REFERENCE))?;
+                Ok(Instant::from(reference) + offset)
+            }
+            None => Ok(Instant::NONE),
+        }
+    }
+}
+
+/// (De)serializes a [`Duration`](crate::Duration) as a single integer of
+/// nanoseconds.
+///
+/// A duration that is not `None` is encoded as `Some(nanos)`, where `nanos` is
+/// a `u64` if it fits (i.e., the duration is shorter than about 584 years),
+/// and a `u128` otherwise. `None` is encoded as `None` (e.g., nil in
+/// MessagePack). Compared with encoding seconds and nanoseconds separately,
+/// this is more compact in schemaless formats such as MessagePack and CBOR.
+///
+/// When deserializing, both `u64` and `u128` are accepted. Returns an error
+/// if the number of nanoseconds is too large to be represented as a
+/// `Duration`.
+///
+/// # Examples
+///
+/// ```
+/// use easytime::{serde::duration_nanos, Duration};
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Config {
+///     #[serde(with = "duration_nanos")]
+///     timeout: Duration,
+/// }
+///
+/// let config = Config { timeout: Duration::from_millis(1_500) };
+/// let json = serde_json::to_string(&config).unwrap();
+/// assert_eq!(json, r#"{"timeout":1500000000}"#);
+///
+/// let config: Config = serde_json::from_str(r#"{"timeout":null}"#).unwrap();
+/// assert!(config.timeout.is_none());
+/// ```
+#[cfg(feature = "serde-compact")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde-compact")))]
+pub mod duration_nanos {
+    use ::serde::{de::Error as _, Deserialize as _, Deserializer, Serializer};
+
+    use crate::Duration;
+
+    /// Serializes a `Duration` as the total number of nanoseconds.
+    pub fn serialize<S>(dur: &Duration, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match dur.as_nanos() {
+            Some(nanos) => match u64::try_from(nanos) {
+                Ok(nanos) => serializer.serialize_some(&nanos),
+                Err(_) => serializer.serialize_some(&nanos),
+            },
+            None => serializer.serialize_none(),
+        }
+    }
+
+    /// Deserializes a `Duration` from the total number of nanoseconds.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match Option::<u128>::deserialize(deserializer)? {
+            Some(nanos) => match Duration::from_nanos_u128(nanos) {
+                dur if dur.is_some() => Ok(dur),
+                _ => Err(D::Error::custom("nanoseconds are out of range for Duration")),
+            },
+            None => Ok(Duration::NONE),
+        }
+    }
+}
+
+/// (De)serializes an `Option<std::time::SystemTime>` as the amount of time
+/// elapsed since [`UNIX_EPOCH`](std::time::UNIX_EPOCH).
+///
+/// The value is encoded as an `Option<std::time::Duration>` (i.e., seconds and
+/// nanoseconds), and `None` is encoded as `None`. When deserializing, a
+/// duration that cannot be added to `UNIX_EPOCH` without overflow yields
+/// `None`.
+///
+/// Serializing a system time earlier than `UNIX_EPOCH` returns an error.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::{Duration, SystemTime, UNIX_EPOCH};
+///
+/// use easytime::serde::system_time_unix;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Entry {
+///     #[serde(with = "system_time_unix")]
+///     modified: Option<SystemTime>,
+/// }
+///
+/// let entry = Entry { modified: Some(UNIX_EPOCH + Duration::new(1, 5)) };
+/// let json = serde_json::to_string(&entry).unwrap();
+/// assert_eq!(json, r#"{"modified":{"secs":1,"nanos":5}}"#);
+///
+/// let entry: Entry = serde_json::from_str(r#"{"modified":null}"#).unwrap();
+/// assert_eq!(entry.modified, None);
+/// ```
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub mod system_time_unix {
+    use std::time;
+
+    use ::serde::{ser::Error as _, Deserialize as _, Deserializer, Serialize as _, Serializer};
+
+    /// Serializes a system time as the `Duration` since `UNIX_EPOCH`.
+    #[allow(clippy::ref_option)] // Signature required by `#[serde(with = "...")]`.
+    pub fn serialize<S>(time: &Option<time::SystemTime>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let since_epoch = match time {
+            Some(time) => Some(
+                time.duration_since(time::UNIX_EPOCH)
+                    .map_err(|_| S::Error::custom("system time is earlier than UNIX_EPOCH"))?,
+            ),
+            None => None,
+        };
+        since_epoch.serialize(serializer)
+    }
+
+    /// Deserializes a system time by adding the `Duration` to `UNIX_EPOCH`.
+    ///
+    /// Returns `None` if the addition overflows.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<time::SystemTime>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let since_epoch = Option::<time::Duration>::deserialize(deserializer)?;
+        Ok(since_epoch.and_then(|d| time::UNIX_EPOCH.checked_add(d)))
+    }
+}
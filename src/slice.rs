@@ -0,0 +1,121 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use crate::{duration::duration_from_nanos_u128, Duration, DurationIteratorExt as _};
+
+mod private {
+    #[allow(unknown_lints, unnameable_types)] // Not public API. unnameable_types is available on Rust 1.79+
+    pub trait Sealed {}
+}
+
+impl private::Sealed for [Duration] {}
+
+/// Extension methods for slices of [`Duration`]s, such as latency samples.
+///
+/// All methods only consider the elements that are not `None`; `None`
+/// elements (e.g., measurements that overflowed) are skipped as if they were
+/// not in the slice. Each method returns `None` if there is no such element.
+///
+/// This trait is sealed and cannot be implemented for types outside of `easytime`.
+pub trait DurationSliceExt: private::Sealed {
+    /// Returns the arithmetic mean of the durations, truncated to the
+    /// nanosecond.
+    ///
+    /// The total is accumulated as 128-bit nanoseconds, so this does not
+    /// overflow even if the sum of the durations is larger than
+    /// [`Duration::MAX`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easytime::{Duration, DurationSliceExt};
+    ///
+    /// let latencies = [Duration::from_millis(10), Duration::NONE, Duration::from_millis(20)];
+    /// assert_eq!(latencies.mean(), Some(Duration::from_millis(15)));
+    /// assert_eq!([Duration::NONE].mean(), None);
+    /// ```
+    #[must_use]
+    fn mean(&self) -> Option<Duration>;
+
+    /// Returns the minimum of the durations.
+    ///
+    /// See also [`DurationIteratorExt::min_some`](crate::DurationIteratorExt::min_some).
+    #[must_use]
+    fn min_some(&self) -> Option<Duration>;
+
+    /// Returns the maximum of the durations.
+    ///
+    /// See also [`DurationIteratorExt::max_some`](crate::DurationIteratorExt::max_some).
+    #[must_use]
+    fn max_some(&self) -> Option<Duration>;
+
+    /// Returns the `p`-th percentile of the durations, where `p` is in
+    /// `[0.0, 100.0]`.
+    ///
+    /// The value is linearly interpolated between the two closest ranks,
+    /// i.e., for `n` sorted durations, the percentile is at rank
+    /// `p / 100 * (n - 1)`. The result is truncated to the nanosecond.
+    ///
+    /// Returns `None` if `p` is not in `[0.0, 100.0]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use easytime::{Duration, DurationSliceExt};
+    ///
+    /// let ms = Duration::from_millis;
+    /// let latencies = [ms(40), ms(10), Duration::NONE, ms(30), ms(20)];
+    /// assert_eq!(latencies.percentile(0.0), Some(ms(10)));
+    /// assert_eq!(latencies.percentile(50.0), Some(ms(25)));
+    /// assert_eq!(latencies.percentile(100.0), Some(ms(40)));
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    #[must_use]
+    fn percentile(&self, p: f64) -> Option<Duration>;
+}
+
+impl DurationSliceExt for [Duration] {
+    fn mean(&self) -> Option<Duration> {
+        let mut total: u128 = 0;
+        let mut count: u128 = 0;
+        for nanos in self.iter().filter_map(Duration::as_nanos) {
+            // Each element is less than 2^94 nanoseconds, so this cannot
+            // overflow unless the slice has more than 2^34 elements.
+            total = total.checked_add(nanos)?;
+            count += 1;
+        }
+        if count == 0 {
+            return None;
+        }
+        Some(Duration(duration_from_nanos_u128(total / count)))
+    }
+
+    fn min_some(&self) -> Option<Duration> {
+        self.iter().copied().min_some()
+    }
+
+    fn max_some(&self) -> Option<Duration> {
+        self.iter().copied().max_some()
+    }
+
+    #[cfg(feature = "alloc")]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss, clippy::cast_sign_loss)]
+    fn percentile(&self, p: f64) -> Option<Duration> {
+        if !(0. ..=100.).contains(&p) {
+            return None;
+        }
+        let mut sorted: alloc::vec::Vec<Duration> =
+            self.iter().copied().filter(Duration::is_some).collect();
+        if sorted.is_empty() {
+            return None;
+        }
+        sorted.sort_unstable();
+        let last = sorted.len() - 1;
+        let rank = p * last as f64 / 100.;
+        // `f64::floor` is not available without std. `rank` is non-negative,
+        // so truncation is equivalent.
+        let lo = rank as usize;
+        let hi = (lo + 1).min(last);
+        Some(sorted[lo].lerp(sorted[hi], rank - lo as f64))
+    }
+}
@@ -0,0 +1,95 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use crate::{Duration, Instant};
+
+/// A stopwatch to measure elapsed time, built on [`Instant`].
+///
+/// All arithmetic is performed using `easytime` types, so it never panics
+/// and a `None` duration is propagated instead.
+///
+/// # Examples
+///
+/// ```
+/// use easytime::Stopwatch;
+///
+/// let mut sw = Stopwatch::start();
+/// // do something...
+/// let first = sw.lap();
+/// // do something else...
+/// let second = sw.lap();
+/// assert!(sw.elapsed() >= first + second);
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub struct Stopwatch {
+    /// The instant at which this stopwatch was last started or resumed, or
+    /// `None` if paused.
+    running_since: Option<Instant>,
+    /// The time accumulated before `running_since`.
+    accumulated: Duration,
+    /// The elapsed time at the last call to `lap`.
+    last_lap: Duration,
+}
+
+impl Stopwatch {
+    /// Creates a new stopwatch and starts it.
+    #[must_use]
+    pub fn start() -> Self {
+        Self {
+            running_since: Some(Instant::now()),
+            accumulated: Duration::ZERO,
+            last_lap: Duration::ZERO,
+        }
+    }
+
+    /// Returns the total time elapsed while this stopwatch was running.
+    ///
+    /// A paused stopwatch stops accumulating time, so this returns the same
+    /// value until it is resumed.
+    #[must_use]
+    pub fn elapsed(&self) -> Duration {
+        match self.running_since {
+            Some(since) => self.accumulated + since.elapsed(),
+            None => self.accumulated,
+        }
+    }
+
+    /// Returns the time elapsed while this stopwatch was running since the
+    /// last call to `lap` (or since the stopwatch was started or reset).
+    pub fn lap(&mut self) -> Duration {
+        let elapsed = self.elapsed();
+        let lap = elapsed - self.last_lap;
+        self.last_lap = elapsed;
+        lap
+    }
+
+    /// Resets the elapsed time to zero. The stopwatch keeps running if it was
+    /// running, and stays paused if it was paused.
+    pub fn reset(&mut self) {
+        if self.running_since.is_some() {
+            self.running_since = Some(Instant::now());
+        }
+        self.accumulated = Duration::ZERO;
+        self.last_lap = Duration::ZERO;
+    }
+
+    /// Pauses this stopwatch. Does nothing if it is already paused.
+    pub fn pause(&mut self) {
+        if let Some(since) = self.running_since.take() {
+            self.accumulated += since.elapsed();
+        }
+    }
+
+    /// Resumes this stopwatch. Does nothing if it is already running.
+    pub fn resume(&mut self) {
+        if self.running_since.is_none() {
+            self.running_since = Some(Instant::now());
+        }
+    }
+
+    /// Returns `true` if this stopwatch is paused.
+    #[must_use]
+    pub fn is_paused(&self) -> bool {
+        self.running_since.is_none()
+    }
+}
@@ -0,0 +1,56 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use crate::Duration;
+
+/// A running total that advances by a fixed step.
+///
+/// All arithmetic is performed using `easytime` types, so it never panics.
+/// Once the total overflows, it stays `None`.
+///
+/// # Examples
+///
+/// ```
+/// use easytime::{Duration, Ticker};
+///
+/// let mut ticker = Ticker::new(Duration::from_millis(250));
+/// assert_eq!(ticker.advance(), Duration::from_millis(250));
+/// assert_eq!(ticker.advance(), Duration::from_millis(500));
+/// assert_eq!(ticker.total(), Duration::from_millis(500));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Ticker {
+    step: Duration,
+    total: Duration,
+}
+
+impl Ticker {
+    /// Creates a new `Ticker` with the given step and a total of zero.
+    #[must_use]
+    pub const fn new(step: Duration) -> Self {
+        Self { step, total: Duration::ZERO }
+    }
+
+    /// Returns the step of this ticker.
+    #[must_use]
+    pub const fn step(&self) -> Duration {
+        self.step
+    }
+
+    /// Returns the total of all steps advanced so far.
+    ///
+    /// Returns [`Duration::NONE`] if the total has overflowed or the step is
+    /// `None`.
+    #[must_use]
+    pub const fn total(&self) -> Duration {
+        self.total
+    }
+
+    /// Adds one step to the total, and returns the new total.
+    ///
+    /// Returns [`Duration::NONE`] if the total overflows or the step is
+    /// `None`.
+    pub fn advance(&mut self) -> Duration {
+        self.total += self.step;
+        self.total
+    }
+}
@@ -0,0 +1,92 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Helpers for using [`Duration`]s with [tokio](https://tokio.rs)'s timers.
+//!
+//! [`tokio::time::sleep`](::tokio::time::sleep) and
+//! [`tokio::time::timeout`](::tokio::time::timeout) take a
+//! [`std::time::Duration`], so a `Duration` that may be `None` has to be
+//! unwrapped first. The functions in this module accept a `Duration`
+//! directly, and return an error instead of panicking if it is `None`.
+
+use core::{fmt, future::Future, time};
+
+use crate::{Duration, TryFromTimeError};
+
+/// Waits until `dur` has elapsed.
+///
+/// If `dur` is `None`, the returned future completes immediately with an
+/// error.
+///
+/// # Examples
+///
+/// ```
+/// use easytime::Duration;
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// easytime::tokio::sleep(Duration::from_millis(10)).await.unwrap();
+/// assert!(easytime::tokio::sleep(Duration::NONE).await.is_err());
+/// # }
+/// ```
+pub fn sleep(dur: Duration) -> impl Future<Output = Result<(), TryFromTimeError>> {
+    let dur = time::Duration::try_from(dur);
+    async move {
+        ::tokio::time::sleep(dur?).await;
+        Ok(())
+    }
+}
+
+/// Requires `future` to complete before `dur` has elapsed.
+///
+/// Returns the output of `future` if it completes in time, or
+/// [`TimeoutError::Elapsed`] otherwise, in which case `future` is dropped.
+///
+/// If `dur` is `None`, the returned future completes immediately with
+/// [`TimeoutError::InvalidDuration`] without polling `future`.
+///
+/// # Examples
+///
+/// ```
+/// use easytime::{tokio::TimeoutError, Duration};
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let res = easytime::tokio::timeout(Duration::from_secs(1), async { 42 }).await;
+/// assert_eq!(res, Ok(42));
+/// let res = easytime::tokio::timeout(Duration::NONE, async { 42 }).await;
+/// assert_eq!(res, Err(TimeoutError::InvalidDuration));
+/// # }
+/// ```
+pub fn timeout<F>(dur: Duration, future: F) -> impl Future<Output = Result<F::Output, TimeoutError>>
+where
+    F: Future,
+{
+    let dur = dur.into_inner();
+    async move {
+        let dur = dur.ok_or(TimeoutError::InvalidDuration)?;
+        ::tokio::time::timeout(dur, future).await.map_err(|_| TimeoutError::Elapsed)
+    }
+}
+
+/// The error type returned by [`timeout`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TimeoutError {
+    /// The duration elapsed before the future completed.
+    Elapsed,
+    /// The duration was `None`.
+    InvalidDuration,
+}
+
+impl fmt::Display for TimeoutError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Elapsed => "deadline has elapsed",
+            Self::InvalidDuration => "timeout duration is invalid",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for TimeoutError {}
@@ -0,0 +1,21 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![cfg(feature = "approx")]
+
+use approx::{abs_diff_eq, assert_abs_diff_eq, assert_abs_diff_ne};
+use easytime::Duration;
+
+#[test]
+fn abs_diff_eq() {
+    let d = Duration::from_millis(1_500);
+    assert_abs_diff_eq!(d, d);
+    assert_abs_diff_ne!(d, d + Duration::from_nanos(1));
+    assert_abs_diff_eq!(d, d + Duration::from_micros(1), epsilon = Duration::from_micros(1));
+    assert_abs_diff_eq!(d + Duration::from_micros(1), d, epsilon = Duration::from_micros(1));
+    assert_abs_diff_ne!(d, d + Duration::new(0, 1_001), epsilon = Duration::from_micros(1));
+
+    // `None` is never approximately equal to anything.
+    assert!(!abs_diff_eq!(Duration::NONE, Duration::NONE));
+    assert!(!abs_diff_eq!(d, Duration::NONE, epsilon = Duration::MAX));
+    assert!(!abs_diff_eq!(d, d, epsilon = Duration::NONE));
+}
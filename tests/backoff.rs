@@ -0,0 +1,56 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use easytime::{Backoff, Duration};
+
+const fn ms(millis: u64) -> Duration {
+    Duration::from_millis(millis)
+}
+
+fn take(backoff: Backoff, n: usize) -> Vec<Duration> {
+    backoff.take(n).collect()
+}
+
+#[test]
+fn exponential() {
+    assert_eq!(
+        take(Backoff::exponential(ms(10), ms(1_000)), 5),
+        [ms(10), ms(20), ms(40), ms(80), ms(160)]
+    );
+    assert_eq!(
+        take(Backoff::exponential(ms(300), ms(1_000)), 4),
+        [ms(300), ms(600), ms(1_000), ms(1_000)]
+    );
+    // The base is also capped.
+    assert_eq!(take(Backoff::exponential(ms(2_000), ms(1_000)), 2), [ms(1_000), ms(1_000)]);
+    assert_eq!(take(Backoff::exponential(Duration::ZERO, ms(1_000)), 3), [Duration::ZERO; 3]);
+    // Overflow is clamped to the cap.
+    let half = Duration::from_secs(u64::MAX / 2 + 1);
+    assert_eq!(
+        take(Backoff::exponential(half, Duration::MAX), 3),
+        [half, Duration::MAX, Duration::MAX]
+    );
+}
+
+#[test]
+fn linear() {
+    assert_eq!(
+        take(Backoff::linear(ms(100), ms(50), ms(250)), 6),
+        [ms(100), ms(150), ms(200), ms(250), ms(250), ms(250)]
+    );
+    assert_eq!(take(Backoff::linear(ms(100), Duration::ZERO, ms(250)), 3), [ms(100); 3]);
+    assert_eq!(
+        take(Backoff::linear(Duration::MAX, Duration::MAX, Duration::MAX), 2),
+        [Duration::MAX; 2]
+    );
+}
+
+#[test]
+fn none() {
+    assert_eq!(Backoff::exponential(Duration::NONE, ms(1_000)).next(), None);
+    assert_eq!(Backoff::exponential(ms(10), Duration::NONE).next(), None);
+    assert_eq!(Backoff::linear(Duration::NONE, ms(10), ms(1_000)).next(), None);
+    assert_eq!(Backoff::linear(ms(10), Duration::NONE, ms(1_000)).next(), None);
+    assert_eq!(Backoff::linear(ms(10), ms(10), Duration::NONE).next(), None);
+    assert_eq!(Backoff::exponential(Duration::NONE, ms(1_000)).size_hint(), (0, Some(0)));
+    assert_eq!(Backoff::exponential(ms(10), ms(1_000)).size_hint(), (usize::MAX, None));
+}
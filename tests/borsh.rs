@@ -0,0 +1,30 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![cfg(feature = "borsh")]
+
+use easytime::Duration;
+
+#[test]
+fn round_trip() {
+    for d in [Duration::NONE, Duration::ZERO, Duration::new(5, 730_023_852), Duration::MAX] {
+        let bytes = borsh::to_vec(&d).unwrap();
+        assert_eq!(borsh::from_slice::<Duration>(&bytes).unwrap(), d);
+    }
+}
+
+#[test]
+fn layout() {
+    assert_eq!(borsh::to_vec(&Duration::NONE).unwrap(), [0]);
+    assert_eq!(
+        borsh::to_vec(&Duration::new(1, 2)).unwrap(),
+        [1, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0]
+    );
+    assert_eq!(
+        borsh::to_vec(&Duration::MAX).unwrap(),
+        [1, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xc9, 0x9a, 0x3b]
+    );
+    assert!(
+        borsh::from_slice::<Duration>(&[1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0xca, 0x9a, 0x3b]).is_err()
+    );
+    assert!(borsh::from_slice::<Duration>(&[2]).is_err());
+}
@@ -0,0 +1,60 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![cfg(feature = "std")]
+
+use std::{cell::Cell, time};
+
+use easytime::{Clock, Duration, Instant, SystemClock};
+
+/// A test clock that only advances when told to.
+struct ManualClock {
+    instant: Cell<Instant>,
+    system: Cell<time::SystemTime>,
+}
+
+impl ManualClock {
+    fn new() -> Self {
+        Self { instant: Cell::new(Instant::now()), system: Cell::new(time::UNIX_EPOCH) }
+    }
+
+    fn advance(&self, dur: time::Duration) {
+        self.instant.set(self.instant.get() + dur);
+        self.system.set(self.system.get() + dur);
+    }
+}
+
+impl Clock for ManualClock {
+    fn now_instant(&self) -> Instant {
+        self.instant.get()
+    }
+
+    fn now_system(&self) -> time::SystemTime {
+        self.system.get()
+    }
+}
+
+fn elapsed_since<C: Clock>(clock: &C, start: Instant) -> Duration {
+    clock.now_instant() - start
+}
+
+#[test]
+fn system_clock() {
+    let before = Instant::now();
+    let now = SystemClock.now_instant();
+    assert!(now >= before);
+    assert!(now <= Instant::now());
+    assert!(SystemClock.now_system() > time::UNIX_EPOCH);
+}
+
+#[test]
+fn manual_clock() {
+    let clock = ManualClock::new();
+    let start = clock.now_instant();
+    assert_eq!(elapsed_since(&clock, start), Duration::ZERO);
+    assert_eq!(clock.now_system(), time::UNIX_EPOCH);
+
+    clock.advance(time::Duration::from_secs(3));
+    assert_eq!(elapsed_since(&clock, start), Duration::from_secs(3));
+    assert_eq!(elapsed_since(&&clock, start), Duration::from_secs(3));
+    assert_eq!(clock.now_system(), time::UNIX_EPOCH + time::Duration::from_secs(3));
+}
@@ -0,0 +1,67 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![cfg(feature = "std")]
+
+use easytime::{Deadline, Duration, Instant};
+
+#[test]
+fn after() {
+    let deadline = Deadline::after(Duration::from_secs(60));
+    assert!(!deadline.is_elapsed());
+    assert!(deadline.remaining() > Duration::ZERO);
+    assert!(deadline.remaining() <= Duration::from_secs(60));
+
+    let deadline = Deadline::after(Duration::ZERO);
+    assert!(deadline.is_elapsed());
+    assert_eq!(deadline.remaining(), Duration::ZERO);
+}
+
+#[test]
+fn remaining_at() {
+    let start = Instant::now();
+    let secs = |s| start + Duration::from_secs(s);
+    let deadline = Deadline::at(secs(10));
+    assert_eq!(deadline.instant(), secs(10));
+
+    assert_eq!(deadline.remaining_at(start), Duration::from_secs(10));
+    assert!(!deadline.is_elapsed_at(start));
+    assert_eq!(deadline.remaining_at(secs(9)), Duration::from_secs(1));
+    assert!(!deadline.is_elapsed_at(secs(9)));
+    assert_eq!(deadline.remaining_at(secs(10)), Duration::ZERO);
+    assert!(deadline.is_elapsed_at(secs(10)));
+    assert_eq!(deadline.remaining_at(secs(11)), Duration::ZERO);
+    assert!(deadline.is_elapsed_at(secs(11)));
+
+    assert!(deadline.remaining_at(Instant::NONE).is_none());
+    assert!(!deadline.is_elapsed_at(Instant::NONE));
+}
+
+#[test]
+fn none() {
+    let start = Instant::now();
+    for deadline in [
+        Deadline::at(Instant::NONE),
+        Deadline::after(Duration::NONE),
+        Deadline::after(Duration::MAX),
+    ] {
+        assert!(deadline.instant().is_none());
+        assert!(deadline.remaining().is_none());
+        assert!(deadline.remaining_at(start).is_none());
+        assert!(!deadline.is_elapsed());
+        assert!(!deadline.is_elapsed_at(start + Duration::from_secs(1)));
+    }
+}
+
+#[test]
+fn min() {
+    let start = Instant::now();
+    let a = Deadline::at(start + Duration::from_secs(1));
+    let b = Deadline::at(start + Duration::from_secs(2));
+    let none = Deadline::at(Instant::NONE);
+    assert_eq!(a.min(b), a);
+    assert_eq!(b.min(a), a);
+    assert_eq!(a.min(a), a);
+    assert_eq!(a.min(none), a);
+    assert_eq!(none.min(a), a);
+    assert_eq!(none.min(none), none);
+}
@@ -1,14 +1,81 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-use core::time;
+use core::{cmp::Ordering, time};
 
-use easytime::Duration;
+use easytime::{Duration, TryFromTimeErrorKind};
 
 #[test]
 fn none() {
     assert!(Duration::NONE.is_none());
 }
 
+#[test]
+fn min() {
+    assert_eq!(Duration::MIN, Duration::ZERO);
+    assert!(Duration::MIN <= Duration::MAX);
+}
+
+#[test]
+fn try_from() {
+    assert_eq!(time::Duration::try_from(Duration::from_secs(1)), Ok(time::Duration::from_secs(1)));
+    let err = time::Duration::try_from(Duration::NONE).unwrap_err();
+    assert_eq!(err.kind(), TryFromTimeErrorKind::Unknown);
+}
+
+#[test]
+fn try_add_sub() {
+    assert_eq!(Duration::new(1, 0).try_add(Duration::new(0, 1)), Ok(Duration::new(1, 1)));
+    assert_eq!(Duration::MAX.try_add(Duration::ZERO), Ok(Duration::MAX));
+    let overflow = Duration::MAX.try_add(Duration::new(0, 1)).unwrap_err();
+    assert_eq!(overflow.kind(), TryFromTimeErrorKind::Overflow);
+    let none = Duration::NONE.try_add(Duration::new(0, 1)).unwrap_err();
+    assert_eq!(none.kind(), TryFromTimeErrorKind::Unknown);
+    let none = Duration::new(0, 1).try_add(Duration::NONE).unwrap_err();
+    assert_eq!(none.kind(), TryFromTimeErrorKind::Unknown);
+
+    assert_eq!(Duration::new(1, 1).try_sub(Duration::new(0, 1)), Ok(Duration::new(1, 0)));
+    assert_eq!(Duration::new(1, 0).try_sub(Duration::new(1, 0)), Ok(Duration::ZERO));
+    let underflow = Duration::ZERO.try_sub(Duration::new(0, 1)).unwrap_err();
+    assert_eq!(underflow.kind(), TryFromTimeErrorKind::Underflow);
+    let none = Duration::NONE.try_sub(Duration::ZERO).unwrap_err();
+    assert_eq!(none.kind(), TryFromTimeErrorKind::Unknown);
+    let none = Duration::MAX.try_sub(Duration::NONE).unwrap_err();
+    assert_eq!(none.kind(), TryFromTimeErrorKind::Unknown);
+
+    assert_ne!(overflow, underflow);
+    assert_ne!(overflow, none);
+}
+
+#[test]
+fn from_u128() {
+    assert_eq!(Duration::from_secs_u128(0), Duration::ZERO);
+    assert_eq!(Duration::from_secs_u128(u128::from(u64::MAX)), Duration::from_secs(u64::MAX));
+    assert!(Duration::from_secs_u128(u128::from(u64::MAX) + 1).is_none());
+
+    assert_eq!(Duration::from_millis_u128(2_569), Duration::from_millis(2_569));
+    assert_eq!(
+        Duration::from_millis_u128(u128::from(u64::MAX) * 1_000 + 999),
+        Duration::new(u64::MAX, 999_000_000)
+    );
+    assert!(Duration::from_millis_u128((u128::from(u64::MAX) + 1) * 1_000).is_none());
+    assert!(Duration::from_millis_u128(u128::MAX).is_none());
+
+    assert_eq!(Duration::from_nanos_u128(1_000_000_123), Duration::new(1, 123));
+    assert!(Duration::from_nanos_u128(Duration::MAX.as_nanos().unwrap() + 1).is_none());
+    assert!(Duration::from_nanos_u128(u128::MAX).is_none());
+
+    for d in [Duration::ZERO, Duration::new(1, 1), Duration::new(u64::MAX / 2, 123), Duration::MAX]
+    {
+        assert_eq!(Duration::from_nanos_u128(d.as_nanos().unwrap()), d);
+        assert_eq!(
+            Duration::from_secs_u128(u128::from(d.as_secs().unwrap())),
+            Duration::from_secs(d.as_secs().unwrap())
+        );
+    }
+    let d = Duration::new(12_345, 678_000_000);
+    assert_eq!(Duration::from_millis_u128(d.as_millis().unwrap()), d);
+}
+
 #[test]
 fn cmp() {
     assert!(Duration::from_secs(1) == Duration::from_secs(1));
@@ -37,6 +104,1110 @@ fn cmp() {
     assert!(time::Duration::from_secs(0) <= Duration::from_secs(1));
 }
 
+#[test]
+fn cmp_nanos() {
+    assert!(Duration::from_secs(1) == 1_000_000_000_u64);
+    assert!(Duration::from_secs(1) != 1_000_000_001_u64);
+    assert!(1_000_000_000_u64 == Duration::from_secs(1));
+    assert!(1_000_000_001_u64 != Duration::from_secs(1));
+    assert!(Duration::MAX != u64::MAX);
+    assert!(Duration::from_nanos(u64::MAX) == u64::MAX);
+
+    assert!(Duration::from_nanos(2) > 1_u64);
+    assert!(Duration::from_nanos(1) < 2_u64);
+    assert!(Duration::from_nanos(1) >= 1_u64);
+    assert!(Duration::from_nanos(1) <= 1_u64);
+    assert!(Duration::MAX > u64::MAX);
+    assert!(2_u64 > Duration::from_nanos(1));
+    assert!(1_u64 < Duration::from_nanos(2));
+    assert!(u64::MAX < Duration::MAX);
+
+    assert!(Duration::NONE != 0_u64);
+    assert!(0_u64 != Duration::NONE);
+    assert_eq!(Duration::NONE.partial_cmp(&0_u64), None);
+    assert_eq!(0_u64.partial_cmp(&Duration::NONE), None);
+    assert!(!(Duration::NONE < 1_u64));
+    assert!(!(Duration::NONE >= 0_u64));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn to_human_string() {
+    assert_eq!(Duration::ZERO.to_human_string().as_deref(), Some("0s"));
+    assert_eq!(Duration::new(0, 1).to_human_string().as_deref(), Some("1ns"));
+    assert_eq!(Duration::new(0, 1_500).to_human_string().as_deref(), Some("1us 500ns"));
+    assert_eq!(
+        Duration::new(0, 123_456_789).to_human_string().as_deref(),
+        Some("123ms 456us 789ns")
+    );
+    assert_eq!(Duration::from_secs(60).to_human_string().as_deref(), Some("1m"));
+    assert_eq!(Duration::from_secs(5_405).to_human_string().as_deref(), Some("1h 30m 5s"));
+    assert_eq!(
+        Duration::new(90_061, 1_000_001).to_human_string().as_deref(),
+        Some("1d 1h 1m 1s 1ms 1ns")
+    );
+    assert_eq!(
+        Duration::MAX.to_human_string().as_deref(),
+        Some("213503982334601d 7h 15s 999ms 999us 999ns")
+    );
+    assert_eq!(Duration::NONE.to_human_string(), None);
+}
+
+#[test]
+fn div_duration_u32() {
+    assert_eq!(Duration::from_secs(9).div_duration_u32(Duration::from_secs(3)), Some(3));
+    assert_eq!(Duration::from_secs(10).div_duration_u32(Duration::from_secs(3)), Some(3));
+    assert_eq!(Duration::from_millis(2_999).div_duration_u32(Duration::from_secs(1)), Some(2));
+    assert_eq!(Duration::from_secs(1).div_duration_u32(Duration::from_secs(2)), Some(0));
+    assert_eq!(
+        Duration::MAX.div_duration_u32(Duration::new(0, 1)),
+        Some(u128::from(u64::MAX) * 1_000_000_000 + 999_999_999)
+    );
+    assert_eq!(Duration::from_secs(10).div_duration_u32(Duration::ZERO), None);
+    assert_eq!(Duration::NONE.div_duration_u32(Duration::from_secs(1)), None);
+    assert_eq!(Duration::from_secs(1).div_duration_u32(Duration::NONE), None);
+}
+
+#[test]
+fn rem() {
+    assert_eq!(Duration::from_secs(10) % Duration::from_secs(3), Duration::from_secs(1));
+    assert_eq!(Duration::from_secs(9) % Duration::from_secs(3), Duration::ZERO);
+    assert_eq!(Duration::new(2, 500) % Duration::from_secs(1), Duration::new(0, 500));
+    assert_eq!(Duration::from_secs(1) % Duration::from_secs(2), Duration::from_secs(1));
+    assert_eq!(Duration::MAX % Duration::new(1, 0), Duration::new(0, 999_999_999));
+    assert_eq!(Duration::from_secs(10) % time::Duration::from_secs(4), Duration::from_secs(2));
+    assert_eq!((Duration::from_secs(10) % Duration::ZERO).into_inner(), None);
+    assert_eq!((Duration::from_secs(10) % time::Duration::ZERO).into_inner(), None);
+    assert_eq!((Duration::NONE % Duration::from_secs(3)).into_inner(), None);
+    assert_eq!((Duration::from_secs(10) % Duration::NONE).into_inner(), None);
+
+    let mut d = Duration::from_secs(10);
+    d %= Duration::from_secs(4);
+    assert_eq!(d, Duration::from_secs(2));
+    d %= time::Duration::from_millis(300);
+    assert_eq!(d, Duration::from_millis(200));
+    d %= Duration::ZERO;
+    assert!(d.is_none());
+}
+
+#[test]
+fn mul_div() {
+    assert_eq!(Duration::from_secs(3).mul_div(2, 3), Duration::from_secs(2));
+    assert_eq!(Duration::from_secs(1).mul_div(1, 3), Duration::new(0, 333_333_333));
+    assert_eq!(Duration::new(0, 10).mul_div(0, 7), Duration::ZERO);
+    assert_eq!(Duration::ZERO.mul_div(u64::MAX, 1), Duration::ZERO);
+    assert_eq!(Duration::MAX.mul_div(1, 1), Duration::MAX);
+
+    // `self * mul` overflows, but the result does not.
+    let dur = Duration::from_secs(u64::MAX / 2);
+    assert!((dur * 3).is_none());
+    assert_eq!(dur.mul_div(3, 3), dur);
+    assert_eq!(Duration::MAX.mul_div(u64::MAX, u64::MAX), Duration::MAX);
+    assert_eq!(
+        Duration::MAX.mul_div(u64::MAX - 1, u64::MAX),
+        Duration::new(u64::MAX - 1, 999_999_998)
+    );
+    // more precise than `self / div * mul`
+    let dur = Duration::new(0, 999_999_999);
+    assert_eq!(dur / 1_000_000 * 1_000, Duration::new(0, 999_000));
+    assert_eq!(dur.mul_div(1_000, 1_000_000), Duration::new(0, 999_999));
+    // agrees with the naive computation where it does not overflow
+    for (dur, mul, div) in [(Duration::new(5, 123_456_789), 7, 11), (Duration::new(1, 1), 3, 2)] {
+        assert_eq!(dur.mul_div(u64::from(mul), u64::from(div)), dur * mul / div);
+    }
+
+    assert!(Duration::from_secs(1).mul_div(1, 0).is_none());
+    assert!(Duration::MAX.mul_div(2, 1).is_none());
+    assert!(Duration::from_secs(u64::MAX / 2).mul_div(u64::MAX, 2).is_none());
+    assert!(Duration::NONE.mul_div(1, 1).is_none());
+}
+
+#[test]
+fn unwrap_or_zero() {
+    assert_eq!(Duration::from_secs(1).unwrap_or_zero(), time::Duration::from_secs(1));
+    assert_eq!(Duration::MAX.unwrap_or_zero(), time::Duration::MAX);
+    assert_eq!(Duration::NONE.unwrap_or_zero(), time::Duration::ZERO);
+    assert_eq!((Duration::ZERO - Duration::from_secs(1)).unwrap_or_zero(), time::Duration::ZERO);
+
+    assert_eq!(Duration::from_secs(1).saturating_into_std(), time::Duration::from_secs(1));
+    assert_eq!(Duration::MAX.saturating_into_std(), time::Duration::MAX);
+    assert_eq!(Duration::NONE.saturating_into_std(), time::Duration::ZERO);
+    assert_eq!(
+        (Duration::MAX + Duration::from_secs(1)).saturating_into_std(),
+        time::Duration::ZERO
+    );
+}
+
+#[test]
+fn checked_mul_pow2() {
+    let base = Duration::from_millis(100);
+    assert_eq!(base.checked_mul_pow2(0), base);
+    assert_eq!(base.checked_mul_pow2(1), Duration::from_millis(200));
+    assert_eq!(base.checked_mul_pow2(4), Duration::from_millis(1_600));
+    assert_eq!(Duration::new(1, 1).checked_mul_pow2(2), Duration::new(4, 4));
+    assert_eq!(Duration::from_nanos(1).checked_mul_pow2(63), Duration::from_nanos(1 << 63));
+    assert_eq!(Duration::ZERO.checked_mul_pow2(u32::MAX), Duration::ZERO);
+    assert_eq!(Duration::MAX.checked_mul_pow2(0), Duration::MAX);
+    assert!(Duration::MAX.checked_mul_pow2(1).is_none());
+    assert!(Duration::from_secs(1).checked_mul_pow2(64).is_none());
+    assert!(Duration::from_nanos(1).checked_mul_pow2(127).is_none());
+    assert!(Duration::from_nanos(1).checked_mul_pow2(128).is_none());
+    assert!(Duration::from_nanos(1).checked_mul_pow2(u32::MAX).is_none());
+    assert!(Duration::NONE.checked_mul_pow2(0).is_none());
+}
+
+#[test]
+fn backoff() {
+    let base = Duration::from_millis(100);
+    let cap = Duration::from_secs(1);
+    assert_eq!(base.backoff(0, cap), Duration::from_millis(100));
+    assert_eq!(base.backoff(1, cap), Duration::from_millis(200));
+    assert_eq!(base.backoff(3, cap), Duration::from_millis(800));
+    assert_eq!(base.backoff(4, cap), cap);
+    assert_eq!(base.backoff(100, cap), cap);
+    assert_eq!(base.backoff(u32::MAX, cap), cap);
+    assert_eq!(base.backoff(0, Duration::ZERO), Duration::ZERO);
+    assert!(Duration::NONE.backoff(0, cap).is_none());
+    assert!(base.backoff(0, Duration::NONE).is_none());
+}
+
+#[test]
+fn as_std() {
+    let dur = Duration::new(1, 2);
+    assert_eq!(dur.as_std(), Some(&time::Duration::new(1, 2)));
+    assert_eq!(dur.as_ref(), &Some(time::Duration::new(1, 2)));
+    assert_eq!(Duration::NONE.as_std(), None);
+    assert_eq!(Duration::NONE.as_ref(), &None);
+}
+
+#[test]
+fn diff() {
+    let a = Duration::new(1, 500_000_000);
+    let b = Duration::new(3, 0);
+    assert_eq!(a.diff(b), (Ordering::Less, Duration::new(1, 500_000_000)));
+    assert_eq!(b.diff(a), (Ordering::Greater, Duration::new(1, 500_000_000)));
+    assert_eq!(a.diff(a), (Ordering::Equal, Duration::ZERO));
+    assert_eq!(Duration::ZERO.diff(Duration::MAX), (Ordering::Less, Duration::MAX));
+    assert_eq!(Duration::MAX.diff(Duration::ZERO), (Ordering::Greater, Duration::MAX));
+
+    let (ord, magnitude) = a.diff(Duration::NONE);
+    assert_eq!(ord, Ordering::Equal);
+    assert!(magnitude.is_none());
+    assert_eq!(Duration::NONE.diff(a).0, Ordering::Equal);
+    assert!(Duration::NONE.diff(Duration::NONE).1.is_none());
+}
+
+#[test]
+fn as_u64_saturating() {
+    assert_eq!(Duration::ZERO.as_secs_u64_saturating(), 0);
+    assert_eq!(Duration::new(5, 999_999_999).as_secs_u64_saturating(), 5);
+    assert_eq!(Duration::MAX.as_secs_u64_saturating(), u64::MAX);
+    assert_eq!(Duration::NONE.as_secs_u64_saturating(), 0);
+
+    assert_eq!(Duration::ZERO.as_millis_u64_saturating(), 0);
+    assert_eq!(Duration::new(0, 999_999).as_millis_u64_saturating(), 0);
+    assert_eq!(Duration::new(5, 730_023_852).as_millis_u64_saturating(), 5_730);
+    assert_eq!(Duration::from_millis(u64::MAX).as_millis_u64_saturating(), u64::MAX);
+    assert_eq!(Duration::from_millis(u64::MAX - 1).as_millis_u64_saturating(), u64::MAX - 1);
+    // The real value exceeds the range of `u64`.
+    assert_eq!(
+        (Duration::from_millis(u64::MAX) + Duration::from_millis(1)).as_millis_u64_saturating(),
+        u64::MAX
+    );
+    assert_eq!(Duration::from_secs(u64::MAX / 1_000 + 1).as_millis_u64_saturating(), u64::MAX);
+    assert_eq!(Duration::MAX.as_millis_u64_saturating(), u64::MAX);
+    assert_eq!(Duration::NONE.as_millis_u64_saturating(), 0);
+}
+
+#[test]
+fn is_max() {
+    const _: () = assert!(Duration::MAX.is_max());
+    assert!(Duration::new(u64::MAX, 999_999_999).is_max());
+    assert!(!Duration::new(u64::MAX, 999_999_998).is_max());
+    assert!(!Duration::new(u64::MAX - 1, 999_999_999).is_max());
+    assert!(!Duration::ZERO.is_max());
+    assert!(!Duration::NONE.is_max());
+}
+
+#[test]
+fn parts() {
+    assert_eq!(Duration::from((5, 730_023_852)), Duration::new(5, 730_023_852));
+    assert_eq!(Duration::from((5, 1_500_000_000)), Duration::new(6, 500_000_000));
+    assert_eq!(Duration::from((u64::MAX, 999_999_999)), Duration::MAX);
+    assert!(Duration::from((u64::MAX, 1_000_000_000)).is_none());
+
+    assert_eq!(Duration::ZERO.to_parts(), Some((0, 0)));
+    assert_eq!(Duration::MAX.to_parts(), Some((u64::MAX, 999_999_999)));
+    assert_eq!(Duration::NONE.to_parts(), None);
+
+    for parts in [(0, 0), (5, 730_023_852), (5, 1_500_000_000), (u64::MAX, 999_999_999)] {
+        let d = Duration::from(parts);
+        assert_eq!(d.to_parts().map(Duration::from), Some(d));
+    }
+    assert_eq!(Duration::from((5, 1_500_000_000)).to_parts(), Some((6, 500_000_000)));
+}
+
+#[test]
+fn offset_nanos() {
+    let dur = Duration::new(1, 500);
+    assert_eq!(dur.offset_nanos(0), dur);
+    assert_eq!(dur.offset_nanos(1), Duration::new(1, 501));
+    assert_eq!(dur.offset_nanos(999_999_500), Duration::new(2, 0));
+    assert_eq!(dur.offset_nanos(i64::MAX), Duration::new(9_223_372_037, 854_776_307));
+    assert_eq!(dur.offset_nanos(-500), Duration::new(1, 0));
+    assert_eq!(dur.offset_nanos(-1_000_000_500), Duration::ZERO);
+    assert!(dur.offset_nanos(-1_000_000_501).is_none());
+    assert!(dur.offset_nanos(i64::MIN).is_none());
+    assert_eq!(Duration::MAX.offset_nanos(-1), Duration::new(u64::MAX, 999_999_998));
+    assert!(Duration::MAX.offset_nanos(1).is_none());
+    assert!(Duration::ZERO.offset_nanos(-1).is_none());
+    assert!(Duration::NONE.offset_nanos(0).is_none());
+}
+
+#[test]
+fn display_inner() {
+    let dur = Duration::new(7, 0);
+    assert_eq!(format!("{:?}", dur.display_inner()), "7s");
+    assert_eq!(format!("{}", dur.display_inner()), "7s");
+    assert_eq!(format!("{:?}", Duration::new(1, 500_000_000).display_inner()), "1.5s");
+    assert_eq!(format!("{:.2?}", Duration::from_micros(1_500).display_inner()), "1.50ms");
+    assert_eq!(format!("{:>5}", Duration::from_nanos(7).display_inner()), "  7ns");
+    assert_eq!(format!("{:?}", Duration::NONE.display_inner()), "None");
+    assert_eq!(format!("{}", Duration::NONE.display_inner()), "None");
+    assert_eq!(format!("{:>6}", Duration::NONE.display_inner()), "  None");
+    // `Debug` of `Duration` itself is unchanged.
+    assert_eq!(format!("{dur:?}"), "Some(7s)");
+    assert_eq!(format!("{:?}", Duration::NONE), "None");
+}
+
+#[test]
+fn new_checked() {
+    assert_eq!(Duration::new_checked(5, 730_023_852), Ok(Duration::new(5, 730_023_852)));
+    assert_eq!(Duration::new_checked(5, 1_500_000_000), Ok(Duration::new(6, 500_000_000)));
+    assert_eq!(Duration::new_checked(u64::MAX, 999_999_999), Ok(Duration::MAX));
+    let err = Duration::new_checked(u64::MAX, 1_000_000_000).unwrap_err();
+    assert_eq!(err.kind(), TryFromTimeErrorKind::Overflow);
+    assert!(Duration::new(u64::MAX, 1_000_000_000).is_none());
+}
+
+#[test]
+fn cmp_secs_f64() {
+    let dur = Duration::new(2, 700_000_000);
+    assert_eq!(dur.cmp_secs_f64(2.7), Some(Ordering::Equal));
+    assert_eq!(dur.cmp_secs_f64(2.5), Some(Ordering::Greater));
+    assert_eq!(dur.cmp_secs_f64(3.0), Some(Ordering::Less));
+    assert_eq!(dur.cmp_secs_f64(-1.0), Some(Ordering::Greater));
+    assert_eq!(Duration::ZERO.cmp_secs_f64(0.0), Some(Ordering::Equal));
+    assert_eq!(Duration::ZERO.cmp_secs_f64(-0.0), Some(Ordering::Equal));
+    assert_eq!(Duration::new(0, 100_000_000).cmp_secs_f64(0.1), Some(Ordering::Equal));
+    // Near float rounding boundaries.
+    assert_eq!(Duration::new(2, 700_000_001).cmp_secs_f64(2.7), Some(Ordering::Greater));
+    assert_eq!(Duration::new(2, 699_999_999).cmp_secs_f64(2.7), Some(Ordering::Less));
+    assert_eq!(Duration::from_nanos(1).cmp_secs_f64(1e-9), Some(Ordering::Equal));
+    // Beyond the precision of `f64`.
+    assert_eq!(Duration::MAX.cmp_secs_f64(1.8446744073709552e19), Some(Ordering::Equal));
+    // `None` and non-finite values.
+    assert_eq!(Duration::NONE.cmp_secs_f64(1.0), None);
+    assert_eq!(dur.cmp_secs_f64(f64::NAN), None);
+    assert_eq!(dur.cmp_secs_f64(f64::INFINITY), None);
+    assert_eq!(dur.cmp_secs_f64(f64::NEG_INFINITY), None);
+}
+
+#[test]
+fn from_nanos_slice() {
+    let max = Duration::MAX.as_nanos().unwrap();
+    let nanos = [0, 1_000_000_123, max + 1, max];
+    let durations: Vec<_> = Duration::from_nanos_slice(&nanos).collect();
+    assert_eq!(durations, [Duration::ZERO, Duration::new(1, 123), Duration::NONE, Duration::MAX]);
+    assert_eq!(Duration::from_nanos_slice(&nanos).len(), 4);
+    assert_eq!(Duration::from_nanos_slice(&[]).next(), None);
+}
+
+#[test]
+fn scale_percent() {
+    assert_eq!(Duration::from_secs(10).scale_percent(90), Duration::from_secs(9));
+    assert_eq!(Duration::from_secs(10).scale_percent(0), Duration::ZERO);
+    assert_eq!(Duration::from_secs(10).scale_percent(100), Duration::from_secs(10));
+    assert_eq!(Duration::from_secs(10).scale_percent(250), Duration::from_secs(25));
+    assert_eq!(Duration::new(1, 1).scale_percent(100), Duration::new(1, 1));
+    // Round half up.
+    assert_eq!(Duration::from_nanos(1).scale_percent(49), Duration::ZERO);
+    assert_eq!(Duration::from_nanos(1).scale_percent(50), Duration::from_nanos(1));
+    assert_eq!(Duration::from_nanos(3).scale_percent(50), Duration::from_nanos(2));
+    assert_eq!(Duration::from_nanos(999).scale_percent(1), Duration::from_nanos(10));
+    // Near `Duration::MAX`.
+    assert_eq!(Duration::MAX.scale_percent(100), Duration::MAX);
+    assert_eq!(Duration::MAX.scale_percent(50), Duration::from_secs(1 << 63));
+    assert!(Duration::MAX.scale_percent(101).is_none());
+    assert!(Duration::MAX.scale_percent(u32::MAX).is_none());
+    assert!(Duration::NONE.scale_percent(100).is_none());
+}
+
+#[test]
+fn bytes() {
+    for d in [Duration::ZERO, Duration::new(5, 730_023_852), Duration::MAX, Duration::NONE] {
+        assert_eq!(Duration::from_le_bytes(d.to_le_bytes()), d);
+        assert_eq!(Duration::from_be_bytes(d.to_be_bytes()), d);
+        // Endianness cross-check.
+        let mut le = d.to_le_bytes();
+        le[..8].reverse();
+        le[8..].reverse();
+        assert_eq!(le, d.to_be_bytes());
+    }
+    assert_eq!(Duration::ZERO.to_le_bytes(), [0; 12]);
+    assert_eq!(Duration::ZERO.to_be_bytes(), [0; 12]);
+    assert_eq!(
+        Duration::MAX.to_le_bytes(),
+        [0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xc9, 0x9a, 0x3b]
+    );
+    assert_eq!(
+        Duration::MAX.to_be_bytes(),
+        [0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x3b, 0x9a, 0xc9, 0xff]
+    );
+    assert_eq!(Duration::NONE.to_le_bytes(), [0xff; 12]);
+    assert_eq!(Duration::NONE.to_be_bytes(), [0xff; 12]);
+    // The nanoseconds part is out of range.
+    assert!(Duration::from_le_bytes([0, 0, 0, 0, 0, 0, 0, 0, 0, 0xca, 0x9a, 0x3b]).is_none());
+    assert!(Duration::from_be_bytes([0, 0, 0, 0, 0, 0, 0, 0, 0x3b, 0x9a, 0xca, 0]).is_none());
+}
+
+#[test]
+fn lerp() {
+    let a = Duration::from_secs(1);
+    let b = Duration::from_secs(3);
+    assert_eq!(a.lerp(b, 0.), a);
+    assert_eq!(a.lerp(b, 1.), b);
+    assert_eq!(a.lerp(b, 0.5), Duration::from_secs(2));
+    assert_eq!(a.lerp(b, 0.25), Duration::new(1, 500_000_000));
+    // `other` is shorter than `self`.
+    assert_eq!(b.lerp(a, 0.), b);
+    assert_eq!(b.lerp(a, 1.), a);
+    assert_eq!(b.lerp(a, 0.5), Duration::from_secs(2));
+    assert_eq!(b.lerp(a, 0.25), Duration::new(2, 500_000_000));
+    // `t` is clamped.
+    assert_eq!(a.lerp(b, -1.), a);
+    assert_eq!(a.lerp(b, 2.), b);
+    assert_eq!(a.lerp(a, 0.5), a);
+    assert_eq!(Duration::ZERO.lerp(Duration::MAX, 1.), Duration::MAX);
+    assert_eq!(Duration::MAX.lerp(Duration::ZERO, 1.), Duration::ZERO);
+    assert!(Duration::ZERO.lerp(Duration::MAX, 0.5) <= Duration::MAX);
+    // `None` and non-finite values.
+    assert!(a.lerp(Duration::NONE, 0.5).is_none());
+    assert!(Duration::NONE.lerp(b, 0.5).is_none());
+    assert!(a.lerp(b, f64::NAN).is_none());
+    assert!(a.lerp(b, f64::INFINITY).is_none());
+    assert!(a.lerp(b, f64::NEG_INFINITY).is_none());
+}
+
+#[test]
+fn from_iter_extend() {
+    let durations = [Duration::from_secs(1), Duration::from_millis(500), Duration::new(0, 1)];
+    let total: Duration = durations.iter().copied().collect();
+    assert_eq!(total, Duration::new(1, 500_000_001));
+    let total: Duration = core::iter::empty().collect();
+    assert_eq!(total, Duration::ZERO);
+    let total: Duration =
+        [Duration::from_secs(1), Duration::NONE, Duration::from_secs(1)].iter().copied().collect();
+    assert!(total.is_none());
+    let total: Duration = [Duration::MAX, Duration::new(0, 1)].iter().copied().collect();
+    assert!(total.is_none());
+
+    let mut total = Duration::from_secs(1);
+    total.extend(durations.iter().copied());
+    assert_eq!(total, Duration::new(2, 500_000_001));
+    total.extend([Duration::NONE]);
+    assert!(total.is_none());
+    total.extend([Duration::ZERO]);
+    assert!(total.is_none());
+}
+
+#[test]
+fn align() {
+    let minute = Duration::from_secs(60);
+    assert_eq!(Duration::ZERO.align_up(minute), Duration::ZERO);
+    assert_eq!(Duration::new(0, 1).align_up(minute), minute);
+    assert_eq!(Duration::from_secs(70).align_up(minute), Duration::from_secs(120));
+    assert_eq!(Duration::from_secs(120).align_up(minute), Duration::from_secs(120));
+    assert_eq!(Duration::new(120, 1).align_up(minute), Duration::from_secs(180));
+    assert_eq!(
+        Duration::from_millis(1_001).align_up(Duration::from_millis(250)),
+        Duration::from_millis(1_250)
+    );
+    assert_eq!(Duration::ZERO.align_down(minute), Duration::ZERO);
+    assert_eq!(Duration::new(0, 1).align_down(minute), Duration::ZERO);
+    assert_eq!(Duration::from_secs(70).align_down(minute), minute);
+    assert_eq!(Duration::from_secs(120).align_down(minute), Duration::from_secs(120));
+    assert_eq!(Duration::new(179, 999_999_999).align_down(minute), Duration::from_secs(120));
+
+    // Zero or `None` period.
+    assert!(Duration::from_secs(70).align_up(Duration::ZERO).is_none());
+    assert!(Duration::from_secs(70).align_down(Duration::ZERO).is_none());
+    assert!(Duration::from_secs(70).align_up(Duration::NONE).is_none());
+    assert!(Duration::from_secs(70).align_down(Duration::NONE).is_none());
+    assert!(Duration::NONE.align_up(minute).is_none());
+    assert!(Duration::NONE.align_down(minute).is_none());
+
+    // Near `Duration::MAX`.
+    assert_eq!(Duration::MAX.align_up(Duration::new(0, 1)), Duration::MAX);
+    assert_eq!(Duration::MAX.align_down(Duration::from_secs(1)), Duration::from_secs(u64::MAX));
+    assert!(Duration::MAX.align_up(Duration::from_secs(1)).is_none());
+    assert!(Duration::from_secs(u64::MAX - 1).align_up(Duration::from_secs(1 << 63)).is_none());
+    assert_eq!(Duration::MAX.align_up(Duration::MAX), Duration::MAX);
+}
+
+#[test]
+fn with_parts() {
+    let d = Duration::new(5, 250);
+    assert_eq!(d.with_secs(7), Duration::new(7, 250));
+    assert_eq!(d.with_secs(0), Duration::from_nanos(250));
+    assert_eq!(d.with_secs(u64::MAX), Duration::new(u64::MAX, 250));
+    assert_eq!(d.with_nanos(0), Duration::from_secs(5));
+    assert_eq!(d.with_nanos(999_999_999), Duration::new(5, 999_999_999));
+
+    // Nanoseconds carry into seconds.
+    assert_eq!(d.with_nanos(1_000_000_000), Duration::from_secs(6));
+    assert_eq!(d.with_nanos(u32::MAX), Duration::new(9, 294_967_295));
+    assert_eq!(
+        Duration::from_secs(u64::MAX - 1).with_nanos(1_000_000_000),
+        Duration::from_secs(u64::MAX)
+    );
+    assert!(Duration::MAX.with_nanos(1_000_000_000).is_none());
+
+    // `None` receiver.
+    assert!(Duration::NONE.with_secs(0).is_none());
+    assert!(Duration::NONE.with_nanos(0).is_none());
+}
+
+#[test]
+fn approx_eq() {
+    let us = Duration::from_micros(1);
+    let a = Duration::new(5, 500_000);
+    assert!(a.approx_eq(a, Duration::ZERO));
+    assert!(a.approx_eq(a + us, us));
+    assert!((a + us).approx_eq(a, us));
+    assert!(!a.approx_eq(a + us + Duration::from_nanos(1), us));
+    assert!(!(a + us + Duration::from_nanos(1)).approx_eq(a, us));
+    assert!(Duration::ZERO.approx_eq(Duration::MAX, Duration::MAX));
+    assert!(!Duration::ZERO.approx_eq(Duration::MAX, Duration::new(u64::MAX, 999_999_998)));
+
+    // `None` operands.
+    assert!(!Duration::NONE.approx_eq(a, Duration::MAX));
+    assert!(!a.approx_eq(Duration::NONE, Duration::MAX));
+    assert!(!a.approx_eq(a, Duration::NONE));
+    assert!(!Duration::NONE.approx_eq(Duration::NONE, Duration::MAX));
+}
+
+#[test]
+fn split_secs() {
+    assert_eq!(
+        Duration::new(5, 730_023_852).split_secs(),
+        (Duration::from_secs(5), Duration::new(0, 730_023_852))
+    );
+    assert_eq!(Duration::ZERO.split_secs(), (Duration::ZERO, Duration::ZERO));
+    assert_eq!(Duration::from_secs(3).split_secs(), (Duration::from_secs(3), Duration::ZERO));
+    assert_eq!(
+        Duration::MAX.split_secs(),
+        (Duration::from_secs(u64::MAX), Duration::from_nanos(999_999_999))
+    );
+    let (secs, frac) = Duration::NONE.split_secs();
+    assert!(secs.is_none());
+    assert!(frac.is_none());
+}
+
+#[test]
+fn checked_div_f64() {
+    let dur = Duration::new(2, 700_000_000);
+    assert_eq!(dur.checked_div_f64(3.5), Ok(dur.div_f64(3.5)));
+    assert_eq!(dur.checked_div_f64(2.0), Ok(Duration::new(1, 350_000_000)));
+    assert_eq!(Duration::ZERO.checked_div_f64(-1.0), Ok(Duration::ZERO));
+
+    for rhs in [0.0, -0.0, f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+        let err = dur.checked_div_f64(rhs).unwrap_err();
+        assert_eq!(err.kind(), TryFromTimeErrorKind::OutOfRange);
+    }
+    let err = Duration::MAX.checked_div_f64(0.5).unwrap_err();
+    assert_eq!(err.kind(), TryFromTimeErrorKind::Overflow);
+    let err = dur.checked_div_f64(f64::MIN_POSITIVE).unwrap_err();
+    assert_eq!(err.kind(), TryFromTimeErrorKind::Overflow);
+    let err = dur.checked_div_f64(-1.0).unwrap_err();
+    assert_eq!(err.kind(), TryFromTimeErrorKind::Underflow);
+    let err = Duration::NONE.checked_div_f64(1.0).unwrap_err();
+    assert_eq!(err.kind(), TryFromTimeErrorKind::Unknown);
+}
+
+#[test]
+fn try_from_secs_float() {
+    assert_eq!(Duration::try_from_secs_f64(0.0), Ok(Duration::ZERO));
+    assert_eq!(Duration::try_from_secs_f64(-0.0), Ok(Duration::ZERO));
+    assert_eq!(Duration::try_from_secs_f64(2.5), Ok(Duration::from_millis(2_500)));
+    assert_eq!(Duration::try_from_secs_f32(2.5), Ok(Duration::from_millis(2_500)));
+
+    for secs in [-1.0, -1e-20, f64::NEG_INFINITY] {
+        let err = Duration::try_from_secs_f64(secs).unwrap_err();
+        assert_eq!(err.kind(), TryFromTimeErrorKind::Underflow);
+    }
+    for secs in [1.8446744073709552e19, f64::MAX, f64::INFINITY] {
+        let err = Duration::try_from_secs_f64(secs).unwrap_err();
+        assert_eq!(err.kind(), TryFromTimeErrorKind::Overflow);
+    }
+    let err = Duration::try_from_secs_f64(f64::NAN).unwrap_err();
+    assert_eq!(err.kind(), TryFromTimeErrorKind::OutOfRange);
+
+    for secs in [-1.0, -1e-20, f32::NEG_INFINITY] {
+        let err = Duration::try_from_secs_f32(secs).unwrap_err();
+        assert_eq!(err.kind(), TryFromTimeErrorKind::Underflow);
+    }
+    for secs in [1.8446744e19, f32::MAX, f32::INFINITY] {
+        let err = Duration::try_from_secs_f32(secs).unwrap_err();
+        assert_eq!(err.kind(), TryFromTimeErrorKind::Overflow);
+    }
+    let err = Duration::try_from_secs_f32(f32::NAN).unwrap_err();
+    assert_eq!(err.kind(), TryFromTimeErrorKind::OutOfRange);
+}
+
+#[test]
+fn cmp_option() {
+    let sec = time::Duration::from_secs(1);
+    let d = Duration::from_secs(1);
+    assert_eq!(d, Some(sec));
+    assert_eq!(Some(sec), d);
+    assert!(d != None);
+    assert!(None != d);
+    assert_eq!(Duration::NONE, None);
+    assert_eq!(None, Duration::NONE);
+    assert!(Duration::NONE != Some(sec));
+    assert!(d != Some(time::Duration::from_secs(2)));
+
+    assert_eq!(d.partial_cmp(&Some(sec)), Some(Ordering::Equal));
+    assert!(d < Some(time::Duration::from_secs(2)));
+    assert!(Some(time::Duration::from_secs(2)) > d);
+    assert!(d > None);
+    assert!(None < d);
+    assert!(Duration::NONE < Some(time::Duration::ZERO));
+    assert_eq!(Duration::NONE.partial_cmp(&None), Some(Ordering::Equal));
+}
+
+#[test]
+fn as_std_mut() {
+    let mut dur = Duration::new(5, 1);
+    *dur.as_std_mut().unwrap() += time::Duration::from_secs(1);
+    assert_eq!(dur, Duration::new(6, 1));
+    if let Some(d) = dur.as_std_mut() {
+        *d = time::Duration::MAX;
+    }
+    assert_eq!(dur, Duration::MAX);
+
+    let mut dur = Duration::NONE;
+    assert_eq!(dur.as_std_mut(), None);
+    assert!(dur.is_none());
+}
+
+#[test]
+fn into_option() {
+    let dur: Duration = Some(time::Duration::from_secs(1)).into();
+    assert_eq!(dur, Duration::from_secs(1));
+    let opt: Option<time::Duration> = dur.into();
+    assert_eq!(opt, Some(time::Duration::from_secs(1)));
+    assert_eq!(Option::<time::Duration>::from(Duration::NONE), None);
+    let dur: Duration = None.into();
+    assert!(dur.is_none());
+}
+
+#[test]
+fn as_or_zero() {
+    let d = Duration::new(5, 730_023_852);
+    assert_eq!(d.as_millis_or_zero(), 5_730);
+    assert_eq!(d.as_micros_or_zero(), 5_730_023);
+    assert_eq!(d.as_nanos_or_zero(), 5_730_023_852);
+    assert_eq!(Duration::MAX.as_nanos_or_zero(), Duration::MAX.as_nanos().unwrap());
+
+    assert_eq!(Duration::NONE.as_millis_or_zero(), 0);
+    assert_eq!(Duration::NONE.as_micros_or_zero(), 0);
+    assert_eq!(Duration::NONE.as_nanos_or_zero(), 0);
+}
+
+#[test]
+fn from_u32() {
+    const SECS: Duration = Duration::from_secs_u32(u32::MAX);
+    const MILLIS: Duration = Duration::from_millis_u32(u32::MAX);
+    const _: () = assert!(SECS.is_some() && MILLIS.is_some());
+    assert_eq!(SECS, Duration::from_secs(u64::from(u32::MAX)));
+    assert_eq!(MILLIS, Duration::new(4_294_967, 295_000_000));
+    assert_eq!(Duration::from_secs_u32(0), Duration::ZERO);
+    assert_eq!(Duration::from_millis_u32(2_569), Duration::from_millis(2_569));
+}
+
+#[test]
+fn as_secs_f64_exact() {
+    assert_eq!(Duration::ZERO.as_secs_f64_exact(), Ok(0.0));
+    assert_eq!(Duration::new(2, 700_000_000).as_secs_f64_exact(), Ok(2.7));
+    assert_eq!(Duration::from_nanos(1).as_secs_f64_exact(), Ok(1e-9));
+    let max_exact = Duration::from_nanos(1 << 53);
+    assert_eq!(max_exact.as_secs_f64_exact(), Ok(9_007_199.254740992));
+
+    let err = (max_exact + Duration::from_nanos(1)).as_secs_f64_exact().unwrap_err();
+    assert_eq!(err.kind(), TryFromTimeErrorKind::PrecisionLoss);
+    let err = Duration::MAX.as_secs_f64_exact().unwrap_err();
+    assert_eq!(err.kind(), TryFromTimeErrorKind::PrecisionLoss);
+    let err = Duration::NONE.as_secs_f64_exact().unwrap_err();
+    assert_eq!(err.kind(), TryFromTimeErrorKind::Unknown);
+}
+
+#[test]
+fn gcd_lcm() {
+    let ms = Duration::from_millis;
+    assert_eq!(ms(40).gcd(ms(60)), ms(20));
+    assert_eq!(ms(40).lcm(ms(60)), ms(120));
+    assert_eq!(ms(60).lcm(ms(40)), ms(120));
+    assert_eq!(ms(60).gcd(ms(60)), ms(60));
+    assert_eq!(ms(60).lcm(ms(60)), ms(60));
+
+    // Coprime periods.
+    assert_eq!(ms(7).gcd(ms(9)), Duration::from_millis(1));
+    assert_eq!(Duration::from_nanos(7).gcd(Duration::from_nanos(9)), Duration::from_nanos(1));
+    assert_eq!(Duration::from_nanos(7).lcm(Duration::from_nanos(9)), Duration::from_nanos(63));
+
+    // Zero.
+    assert_eq!(Duration::ZERO.gcd(ms(5)), ms(5));
+    assert_eq!(ms(5).gcd(Duration::ZERO), ms(5));
+    assert_eq!(Duration::ZERO.gcd(Duration::ZERO), Duration::ZERO);
+    assert_eq!(Duration::ZERO.lcm(ms(5)), Duration::ZERO);
+    assert_eq!(ms(5).lcm(Duration::ZERO), Duration::ZERO);
+
+    // Overflow.
+    assert_eq!(Duration::MAX.lcm(Duration::from_nanos(1)), Duration::MAX);
+    assert!(Duration::from_secs(u64::MAX).lcm(Duration::from_nanos(7)).is_none());
+    assert!(Duration::MAX.lcm(Duration::MAX - Duration::from_nanos(1)).is_none());
+    assert!(Duration::from_secs(1 << 40).lcm(Duration::new(1 << 40, 1)).is_none());
+
+    // `None`.
+    assert!(Duration::NONE.gcd(ms(1)).is_none());
+    assert!(ms(1).gcd(Duration::NONE).is_none());
+    assert!(Duration::NONE.lcm(ms(1)).is_none());
+    assert!(Duration::ZERO.lcm(Duration::NONE).is_none());
+}
+
+#[test]
+fn within() {
+    let tol = Duration::DEFAULT_TEST_TOLERANCE;
+    assert_eq!(tol, Duration::from_micros(1));
+    let d = Duration::from_secs(1);
+    assert!(d.within(d, Duration::ZERO));
+    assert!(d.within(d + tol, tol));
+    assert!((d + tol).within(d, tol));
+    assert!(!d.within(d + tol + Duration::from_nanos(1), tol));
+    assert!(!(d - tol - Duration::from_nanos(1)).within(d, tol));
+    assert!(!d.within(Duration::NONE, Duration::MAX));
+    assert!(!Duration::NONE.within(d, Duration::MAX));
+    assert!(!d.within(d, Duration::NONE));
+}
+
+#[test]
+fn sum_capped() {
+    let secs = Duration::from_secs;
+    let cap = secs(10);
+    assert_eq!(Duration::sum_capped(&[], cap), Duration::ZERO);
+    assert_eq!(Duration::sum_capped(&[secs(1), secs(2)], cap), secs(3));
+    assert_eq!(Duration::sum_capped(&[secs(4), secs(6)], cap), cap);
+    assert_eq!(Duration::sum_capped(&[secs(4), secs(8)], cap), cap);
+    assert_eq!(Duration::sum_capped(&[Duration::MAX, Duration::MAX], cap), cap);
+    assert_eq!(Duration::sum_capped(&[Duration::MAX, Duration::MAX], Duration::MAX), Duration::MAX);
+    assert_eq!(Duration::sum_capped(&[secs(1)], Duration::ZERO), Duration::ZERO);
+
+    assert!(Duration::sum_capped(&[secs(1), Duration::NONE], cap).is_none());
+    // Poisons even if the cap has already been reached.
+    assert!(Duration::sum_capped(&[secs(20), Duration::NONE], cap).is_none());
+    assert!(Duration::sum_capped(&[secs(1)], Duration::NONE).is_none());
+}
+
+#[test]
+fn checked_mul_float() {
+    let dur = Duration::new(2, 700_000_000);
+    assert_eq!(dur.checked_mul_f64(3.5), Ok(dur.mul_f64(3.5)));
+    assert_eq!(dur.checked_mul_f64(0.0), Ok(Duration::ZERO));
+    assert_eq!(dur.checked_mul_f64(-0.0), Ok(Duration::ZERO));
+    assert_eq!(Duration::ZERO.checked_mul_f64(-1.0), Ok(Duration::ZERO));
+    assert_eq!(dur.checked_mul_f32(2.0), Ok(Duration::new(5, 400_000_000)));
+
+    for rhs in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+        let err = dur.checked_mul_f64(rhs).unwrap_err();
+        assert_eq!(err.kind(), TryFromTimeErrorKind::OutOfRange);
+    }
+    let err = dur.checked_mul_f64(-1.0).unwrap_err();
+    assert_eq!(err.kind(), TryFromTimeErrorKind::Underflow);
+    let err = Duration::MAX.checked_mul_f64(2.0).unwrap_err();
+    assert_eq!(err.kind(), TryFromTimeErrorKind::Overflow);
+    let err = dur.checked_mul_f64(f64::MAX).unwrap_err();
+    assert_eq!(err.kind(), TryFromTimeErrorKind::Overflow);
+    let err = Duration::NONE.checked_mul_f64(1.0).unwrap_err();
+    assert_eq!(err.kind(), TryFromTimeErrorKind::Unknown);
+
+    for rhs in [f32::NAN, f32::INFINITY, f32::NEG_INFINITY] {
+        let err = dur.checked_mul_f32(rhs).unwrap_err();
+        assert_eq!(err.kind(), TryFromTimeErrorKind::OutOfRange);
+    }
+    let err = dur.checked_mul_f32(-1.0).unwrap_err();
+    assert_eq!(err.kind(), TryFromTimeErrorKind::Underflow);
+    let err = dur.checked_mul_f32(f32::MAX).unwrap_err();
+    assert_eq!(err.kind(), TryFromTimeErrorKind::Overflow);
+    let err = Duration::NONE.checked_mul_f32(1.0).unwrap_err();
+    assert_eq!(err.kind(), TryFromTimeErrorKind::Unknown);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn format_as() {
+    use easytime::DurationUnit;
+
+    let f = |d: Duration, unit, precision| d.format_as(unit, precision).unwrap();
+    let d = Duration::new(1, 500_000_000);
+    assert_eq!(f(d, DurationUnit::Nanos, 0), "1500000000ns");
+    assert_eq!(f(d, DurationUnit::Nanos, 2), "1500000000.00ns");
+    assert_eq!(f(d, DurationUnit::Micros, 0), "1500000µs");
+    assert_eq!(f(d, DurationUnit::Millis, 2), "1500.00ms");
+    assert_eq!(f(d, DurationUnit::Secs, 0), "2s");
+    assert_eq!(f(d, DurationUnit::Secs, 1), "1.5s");
+    assert_eq!(f(d, DurationUnit::Secs, 12), "1.500000000000s");
+
+    // Rounding at the precision boundary.
+    let d = Duration::new(0, 1_234_500);
+    assert_eq!(f(d, DurationUnit::Millis, 3), "1.235ms");
+    assert_eq!(f(d, DurationUnit::Millis, 4), "1.2345ms");
+    assert_eq!(f(d, DurationUnit::Millis, 2), "1.23ms");
+    assert_eq!(f(Duration::new(0, 1_234_499), DurationUnit::Millis, 3), "1.234ms");
+    assert_eq!(f(Duration::new(0, 999_999_999), DurationUnit::Secs, 3), "1.000s");
+    assert_eq!(f(Duration::new(0, 999_499_999), DurationUnit::Secs, 3), "0.999s");
+    assert_eq!(f(Duration::ZERO, DurationUnit::Secs, 2), "0.00s");
+
+    assert_eq!(f(Duration::MAX, DurationUnit::Secs, 9), "18446744073709551615.999999999s");
+    assert_eq!(f(Duration::MAX, DurationUnit::Secs, 0), "18446744073709551616s");
+    assert_eq!(Duration::NONE.format_as(DurationUnit::Millis, 2), None);
+}
+
+#[test]
+fn wrap() {
+    let period = Duration::from_millis(250);
+    assert_eq!(Duration::ZERO.wrap(period), Duration::ZERO);
+    assert_eq!(Duration::from_millis(100).wrap(period), Duration::from_millis(100));
+    assert_eq!(Duration::from_millis(250).wrap(period), Duration::ZERO);
+    assert_eq!(Duration::from_millis(1_100).wrap(period), Duration::from_millis(100));
+    assert_eq!(Duration::MAX.wrap(Duration::MAX), Duration::ZERO);
+    assert_eq!(Duration::MAX.wrap(Duration::from_nanos(1)), Duration::ZERO);
+
+    assert!(Duration::from_secs(1).wrap(Duration::ZERO).is_none());
+    assert!(Duration::NONE.wrap(period).is_none());
+    assert!(Duration::from_secs(1).wrap(Duration::NONE).is_none());
+}
+
+#[test]
+fn try_from_duration_for_primitives() {
+    let dur = Duration::new(1, 500_999_999);
+    assert_eq!(dur.try_as_millis_u64(), Ok(1_500));
+    assert_eq!(u128::try_from(dur), Ok(1_500_999_999));
+    assert_eq!(f64::try_from(dur), Ok(1.500_999_999));
+    assert_eq!(Duration::from_millis(u64::MAX).try_as_millis_u64(), Ok(u64::MAX));
+    assert_eq!(u128::try_from(Duration::MAX), Ok(Duration::MAX.as_nanos().unwrap()));
+
+    let err = (Duration::from_millis(u64::MAX) + Duration::from_millis(1))
+        .try_as_millis_u64()
+        .unwrap_err();
+    assert_eq!(err.kind(), TryFromTimeErrorKind::Overflow);
+    assert_eq!(
+        Duration::MAX.try_as_millis_u64().unwrap_err().kind(),
+        TryFromTimeErrorKind::Overflow
+    );
+
+    let out_of_range = TryFromTimeErrorKind::OutOfRange;
+    assert_eq!(Duration::NONE.try_as_millis_u64().unwrap_err().kind(), out_of_range);
+    assert_eq!(u128::try_from(Duration::NONE).unwrap_err().kind(), out_of_range);
+    assert_eq!(f64::try_from(Duration::NONE).unwrap_err().kind(), out_of_range);
+}
+
+#[test]
+fn from_parts_u128() {
+    assert_eq!(Duration::from_parts_u128(0, 0), Duration::ZERO);
+    assert_eq!(Duration::from_parts_u128(1, 2), Duration::new(1, 2));
+    assert_eq!(Duration::from_parts_u128(1, 2_000_000_001), Duration::new(3, 1));
+    assert_eq!(Duration::from_parts_u128(u128::from(u64::MAX), 999_999_999), Duration::MAX);
+    assert!(Duration::from_parts_u128(u128::from(u64::MAX), 1_000_000_000).is_none());
+    assert!(Duration::from_parts_u128(u128::from(u64::MAX) + 1, 0).is_none());
+    assert!(Duration::from_parts_u128(u128::MAX, 0).is_none());
+}
+
+#[test]
+fn from_secs_f64_rounded() {
+    use easytime::RoundMode;
+
+    let f = Duration::from_secs_f64_rounded;
+    let ns = Duration::from_nanos;
+    // The nanosecond part is exactly `x.5`.
+    assert_eq!(f(2.5e-9, RoundMode::Trunc), ns(2));
+    assert_eq!(f(2.5e-9, RoundMode::Down), ns(2));
+    assert_eq!(f(2.5e-9, RoundMode::Up), ns(3));
+    assert_eq!(f(2.5e-9, RoundMode::Nearest), ns(2));
+    assert_eq!(f(3.5e-9, RoundMode::Trunc), ns(3));
+    assert_eq!(f(3.5e-9, RoundMode::Down), ns(3));
+    assert_eq!(f(3.5e-9, RoundMode::Up), ns(4));
+    assert_eq!(f(3.5e-9, RoundMode::Nearest), ns(4));
+    assert_eq!(f(1.000_000_002_5, RoundMode::Nearest), Duration::new(1, 2));
+    assert_eq!(f(1.000_000_002_5, RoundMode::Up), Duration::new(1, 3));
+    assert_eq!(f(1.6e-9, RoundMode::Nearest), ns(2));
+    assert_eq!(f(1.4e-9, RoundMode::Nearest), ns(1));
+    assert_eq!(f(2.0, RoundMode::Up), Duration::from_secs(2));
+
+    for mode in [RoundMode::Trunc, RoundMode::Nearest, RoundMode::Up, RoundMode::Down] {
+        assert_eq!(f(0.0, mode), Duration::ZERO);
+        assert!(f(-1.0, mode).is_none());
+        assert!(f(f64::NAN, mode).is_none());
+        assert!(f(f64::INFINITY, mode).is_none());
+        assert!(f(4e19, mode).is_none());
+    }
+    for secs in [0.0, 2.7, 1e-10, 12_345.678_9, 1e15] {
+        assert_eq!(f(secs, RoundMode::Trunc), Duration::from_secs_f64(secs));
+    }
+}
+
+#[test]
+fn accumulate() {
+    let max = Duration::from_secs(1);
+    let step = Duration::from_millis(400);
+    let mut d = Duration::ZERO;
+    assert!(!d.accumulate(step, max));
+    assert_eq!(d, Duration::from_millis(400));
+    assert!(!d.accumulate(step, max));
+    assert_eq!(d, Duration::from_millis(800));
+    assert!(d.accumulate(step, max));
+    assert_eq!(d, max);
+    assert!(d.accumulate(step, max));
+    assert_eq!(d, max);
+
+    // Reaching the ceiling exactly.
+    let mut d = Duration::from_millis(600);
+    assert!(d.accumulate(step, max));
+    assert_eq!(d, max);
+    let mut d = Duration::ZERO;
+    assert!(d.accumulate(Duration::ZERO, Duration::ZERO));
+    // Above the ceiling.
+    let mut d = Duration::from_secs(2);
+    assert!(d.accumulate(Duration::ZERO, max));
+    assert_eq!(d, max);
+    // Overflow is clamped.
+    let mut d = Duration::MAX;
+    assert!(d.accumulate(Duration::MAX, Duration::MAX));
+    assert_eq!(d, Duration::MAX);
+
+    let mut d = Duration::NONE;
+    assert!(!d.accumulate(step, max));
+    assert!(d.is_none());
+    let mut d = Duration::ZERO;
+    assert!(!d.accumulate(Duration::NONE, max));
+    assert!(d.is_none());
+    let mut d = Duration::ZERO;
+    assert!(!d.accumulate(step, Duration::NONE));
+    assert!(d.is_none());
+}
+
+#[test]
+fn cmp_secs_f64_precision() {
+    // Float and exact comparisons differ when nanosecond precision is lost.
+    for secs in [1 << 40, 1 << 53, u64::MAX - 1] {
+        let a = Duration::from_secs(secs);
+        let b = a + Duration::from_nanos(1);
+        assert_eq!(a.cmp_as_secs_f64(&b), Some(Ordering::Equal));
+        assert_eq!(b.cmp_as_secs_f64(&a), Some(Ordering::Equal));
+        assert_eq!(a.cmp(&b), Ordering::Less);
+    }
+    let a = Duration::from_secs(1 << 20);
+    let b = a + Duration::from_nanos(1);
+    assert_eq!(a.cmp_as_secs_f64(&b), Some(Ordering::Less));
+    assert_eq!(b.cmp_as_secs_f64(&a), Some(Ordering::Greater));
+    assert_eq!(Duration::MAX.cmp_as_secs_f64(&Duration::ZERO), Some(Ordering::Greater));
+
+    assert_eq!(a.cmp_as_secs_f64(&Duration::NONE), None);
+    assert_eq!(Duration::NONE.cmp_as_secs_f64(&a), None);
+    assert_eq!(Duration::NONE.cmp_as_secs_f64(&Duration::NONE), None);
+}
+
+#[test]
+fn div_f64() {
+    let dur = Duration::new(2, 700_000_000);
+    for (rhs, expected) in [
+        (1.0, dur),
+        (2.0, Duration::new(1, 350_000_000)),
+        (0.5, Duration::new(5, 400_000_000)),
+        (3.5, Duration::new(0, 771_428_571)),
+        (f64::MAX, Duration::ZERO),
+        (0.0, Duration::NONE),
+        (-0.0, Duration::NONE),
+        (-1.0, Duration::NONE),
+        (f64::MIN_POSITIVE, Duration::NONE),
+        (f64::INFINITY, Duration::NONE),
+        (f64::NEG_INFINITY, Duration::NONE),
+        (f64::NAN, Duration::NONE),
+    ] {
+        assert_eq!(dur.div_f64(rhs), expected, "{rhs}");
+        assert_eq!(
+            dur.checked_div_f64(rhs).ok(),
+            expected.into_inner().map(Duration::from),
+            "{rhs}"
+        );
+    }
+    assert_eq!(Duration::ZERO.div_f64(2.0), Duration::ZERO);
+    assert!(Duration::ZERO.div_f64(-1.0).is_none());
+    assert!(Duration::ZERO.div_f64(-0.0).is_none());
+    assert!(Duration::NONE.div_f64(1.0).is_none());
+}
+
+#[test]
+fn div_f32() {
+    let dur = Duration::new(2, 700_000_000);
+    for (rhs, expected) in [
+        (1.0, dur),
+        (2.0, Duration::new(1, 350_000_000)),
+        (3.5, Duration::new(0, 771_428_608)),
+        (f32::MAX, Duration::ZERO),
+        (0.0, Duration::NONE),
+        (-0.0, Duration::NONE),
+        (-1.0, Duration::NONE),
+        (f32::MIN_POSITIVE, Duration::NONE),
+        (f32::INFINITY, Duration::NONE),
+        (f32::NEG_INFINITY, Duration::NONE),
+        (f32::NAN, Duration::NONE),
+    ] {
+        assert_eq!(dur.div_f32(rhs), expected, "{rhs}");
+    }
+    assert_eq!(Duration::ZERO.div_f32(2.0), Duration::ZERO);
+    assert!(Duration::ZERO.div_f32(-1.0).is_none());
+    assert!(Duration::ZERO.div_f32(-0.0).is_none());
+    assert!(Duration::NONE.div_f32(1.0).is_none());
+}
+
+#[test]
+fn checked_scale() {
+    let dur = Duration::new(1, 500_000_000);
+    assert_eq!(dur.checked_scale(3_usize), Duration::new(4, 500_000_000));
+    assert_eq!(dur.checked_scale(0_usize), Duration::ZERO);
+    assert_eq!(dur.checked_scale(2_i32), Duration::from_secs(3));
+    assert_eq!(dur.checked_scale(u16::MAX), Duration::new(98_302, 500_000_000));
+    assert_eq!(dur.checked_scale(4_u8), Duration::from_secs(6));
+    assert_eq!(Duration::from_nanos(1).checked_scale(u64::MAX), Duration::from_nanos(u64::MAX));
+    assert_eq!(Duration::MAX.checked_scale(1_u64), Duration::MAX);
+
+    assert!(dur.checked_scale(-1_i32).is_none());
+    assert!(dur.checked_scale(i64::MIN).is_none());
+    assert!(dur.checked_scale(u128::from(u64::MAX) + 1).is_none());
+    assert!(Duration::MAX.checked_scale(2_u16).is_none());
+    assert!(Duration::NONE.checked_scale(1_usize).is_none());
+}
+
+#[test]
+fn midpoint() {
+    let a = Duration::new(1, 500_000_000);
+    let b = Duration::from_secs(3);
+    assert_eq!(a.midpoint(b), Duration::new(2, 250_000_000));
+    assert_eq!(b.midpoint(a), Duration::new(2, 250_000_000));
+    assert_eq!(a.midpoint(a), a);
+    assert_eq!(Duration::ZERO.midpoint(Duration::from_nanos(3)), Duration::from_nanos(1));
+    assert_eq!(Duration::ZERO.midpoint(Duration::MAX), Duration::new(u64::MAX / 2, 999_999_999));
+
+    // The naive average would overflow.
+    let near_max = Duration::MAX - Duration::from_secs(2);
+    assert!((near_max + Duration::MAX).is_none());
+    assert_eq!(near_max.midpoint(Duration::MAX), Duration::MAX - Duration::from_secs(1));
+    assert_eq!(Duration::MAX.midpoint(Duration::MAX), Duration::MAX);
+
+    assert!(a.midpoint(Duration::NONE).is_none());
+    assert!(Duration::NONE.midpoint(a).is_none());
+}
+
+#[test]
+fn try_from_secs_float_std_cases() {
+    // Cases similar to those documented for `std::time::Duration::try_from_secs_f64`.
+    assert_eq!(Duration::try_from_secs_f64(0.0), Ok(Duration::ZERO));
+    assert_eq!(Duration::try_from_secs_f64(1e-20), Ok(Duration::ZERO));
+    assert_eq!(Duration::try_from_secs_f64(4.2e-7), Ok(Duration::from_nanos(420)));
+    assert_eq!(Duration::try_from_secs_f64(2.7), Ok(Duration::new(2, 700_000_000)));
+    assert_eq!(Duration::try_from_secs_f64(3e10), Ok(Duration::from_secs(30_000_000_000)));
+    assert_eq!(Duration::try_from_secs_f64(f64::from_bits(1)), Ok(Duration::ZERO));
+    assert_eq!(
+        Duration::try_from_secs_f64(-5.0).unwrap_err().kind(),
+        TryFromTimeErrorKind::Underflow
+    );
+    assert_eq!(
+        Duration::try_from_secs_f64(f64::NAN).unwrap_err().kind(),
+        TryFromTimeErrorKind::OutOfRange
+    );
+    assert_eq!(
+        Duration::try_from_secs_f64(f64::INFINITY).unwrap_err().kind(),
+        TryFromTimeErrorKind::Overflow
+    );
+    // The standard library rounds to the nearest nanosecond, but this truncates.
+    assert_eq!(Duration::try_from_secs_f64(0.999e-9), Ok(Duration::ZERO));
+
+    // Cases similar to those documented for `std::time::Duration::try_from_secs_f32`.
+    assert_eq!(Duration::try_from_secs_f32(0.0), Ok(Duration::ZERO));
+    assert_eq!(Duration::try_from_secs_f32(1e-20), Ok(Duration::ZERO));
+    assert_eq!(Duration::try_from_secs_f32(f32::from_bits(1)), Ok(Duration::ZERO));
+    assert_eq!(
+        Duration::try_from_secs_f32(-5.0).unwrap_err().kind(),
+        TryFromTimeErrorKind::Underflow
+    );
+    assert_eq!(
+        Duration::try_from_secs_f32(f32::NAN).unwrap_err().kind(),
+        TryFromTimeErrorKind::OutOfRange
+    );
+    assert_eq!(
+        Duration::try_from_secs_f32(f32::INFINITY).unwrap_err().kind(),
+        TryFromTimeErrorKind::Overflow
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn to_human_string_humantime_round_trip() {
+    for dur in [
+        Duration::ZERO,
+        Duration::from_nanos(1),
+        Duration::new(0, 1_500),
+        Duration::from_micros(1_500),
+        Duration::new(5_405, 0),
+        Duration::new(90_061, 1_001_001),
+        // 30 days or more, which `humantime::format_duration` writes in months.
+        Duration::from_secs(30 * 86_400),
+        Duration::new(400 * 86_400 + 1, 1),
+        Duration::MAX,
+    ] {
+        let s = dur.to_human_string().unwrap();
+        assert_eq!(humantime::parse_duration(&s).map(Duration::from), Ok(dur), "{s}");
+    }
+}
+
+#[test]
+#[allow(clippy::cast_precision_loss)] // Rounding `u64::MAX` up to 2^64 is intended.
+fn try_from_secs_float_upper_bound() {
+    // `u64::MAX as f64` is 2^64, which is larger than `Duration::MAX`.
+    assert_eq!(
+        Duration::try_from_secs_f64(u64::MAX as f64).unwrap_err().kind(),
+        TryFromTimeErrorKind::Overflow
+    );
+    assert_eq!(
+        Duration::try_from_secs_f32(u64::MAX as f32).unwrap_err().kind(),
+        TryFromTimeErrorKind::Overflow
+    );
+    // The largest values below 2^64. The conversion goes through nanoseconds
+    // in floating point, so the result differs slightly from the input (and
+    // from the standard library, which returns 18_446_744_073_709_549_568s).
+    let secs = f64::from_bits((u64::MAX as f64).to_bits() - 1);
+    assert_eq!(
+        Duration::try_from_secs_f64(secs),
+        Ok(Duration::new(18_446_744_073_709_549_416, 976_744_448))
+    );
+    let secs = f32::from_bits((u64::MAX as f32).to_bits() - 1);
+    let dur = Duration::try_from_secs_f32(secs).unwrap();
+    assert!(dur > Duration::from_secs(18_446_742_000_000_000_000));
+    assert!(dur < Duration::MAX);
+}
+
 // https://github.com/rust-lang/rust/blob/1.63.0/library/core/tests/time.rs
 mod core_tests {
     #![allow(
@@ -403,6 +1574,9 @@ mod core_tests {
         const IS_ZERO: bool = Duration::ZERO.is_zero();
         assert!(IS_ZERO);
 
+        const MIN_IS_ZERO: bool = Duration::MIN.is_zero();
+        assert!(MIN_IS_ZERO);
+
         const SECONDS: Option<u64> = duration_second().as_secs();
         assert_eq!(SECONDS, Some(1));
 
@@ -0,0 +1,25 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![cfg(feature = "float-cmp")]
+
+use easytime::Duration;
+use float_cmp::{ApproxEq, F64Margin};
+
+#[test]
+fn approx_eq() {
+    let a = Duration::new(1, 500_000_000);
+    let b = a + Duration::from_nanos(1);
+    assert!(ApproxEq::approx_eq(a, a, F64Margin::default()));
+    assert!(!ApproxEq::approx_eq(a, b, F64Margin::default()));
+    assert!(ApproxEq::approx_eq(a, b, F64Margin { epsilon: 2e-9, ulps: 0 }));
+    assert!(ApproxEq::approx_eq(b, a, (2e-9, 0)));
+    assert!(ApproxEq::approx_ne(a, a + Duration::from_micros(1), (2e-9, 0)));
+    // 1ns at 1.5s is about 4.5 million ULPs of `f64`.
+    assert!(ApproxEq::approx_eq(a, b, (0.0, 5_000_000)));
+    assert!(!ApproxEq::approx_eq(a, b, (0.0, 4_000_000)));
+
+    // `None` is never approximately equal to anything.
+    assert!(!ApproxEq::approx_eq(Duration::NONE, Duration::NONE, (1.0, 0)));
+    assert!(!ApproxEq::approx_eq(a, Duration::NONE, (f64::MAX, i64::MAX)));
+    assert!(!ApproxEq::approx_eq(Duration::NONE, a, (f64::MAX, i64::MAX)));
+}
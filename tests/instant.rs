@@ -2,26 +2,259 @@
 
 #![cfg(feature = "std")]
 
+use easytime::{assert_duration_almost_eq, Duration, Instant, TryFromTimeErrorKind};
+
+#[test]
+fn try_now() {
+    let before = Instant::now();
+    let now = Instant::try_now();
+    assert!(now.is_some());
+    assert!(now >= before);
+    assert!(now <= Instant::now());
+}
+
+#[test]
+fn after_before() {
+    let now = Instant::now();
+    let after = Instant::after(Duration::from_secs(1));
+    assert!(after > Instant::now());
+    assert!(after >= now + Duration::from_secs(1));
+    let before = Instant::before(Duration::from_secs(1));
+    assert!(before < now);
+    assert!(Instant::after(Duration::from_secs(u64::MAX)).is_none());
+    assert!(Instant::after(Duration::NONE).is_none());
+    assert!(Instant::before(Duration::NONE).is_none());
+}
+
+#[test]
+fn try_duration_since() {
+    let now = Instant::now();
+    let earlier = now - Duration::new(1, 0);
+    let later = now + Duration::new(1, 0);
+    assert_eq!(later.try_duration_since(now), Ok(Duration::new(1, 0)));
+    assert_eq!(now.try_duration_since(now), Ok(Duration::ZERO));
+    assert!(earlier.try_duration_since(now).is_err());
+    assert!(now.try_duration_since(Instant::NONE).is_err());
+    assert!(Instant::NONE.try_duration_since(now).is_err());
+
+    let underflow = earlier.try_duration_since(now).unwrap_err();
+    assert_eq!(underflow.kind(), TryFromTimeErrorKind::Underflow);
+    let unknown = Instant::NONE.try_duration_since(now).unwrap_err();
+    assert_eq!(unknown.kind(), TryFromTimeErrorKind::Unknown);
+    assert_ne!(underflow, unknown);
+    assert_ne!(underflow.to_string(), unknown.to_string());
+}
+
+#[test]
+fn combinators() {
+    let now = Instant::now();
+    let sec = std::time::Duration::from_secs(1);
+
+    assert_eq!(now.map(|i| i + sec), now + sec);
+    assert!(Instant::NONE.map(|i| i + sec).is_none());
+
+    assert_eq!(now.and_then(|i| i.checked_sub(sec)), now - sec);
+    assert!(now.and_then(|_| None).is_none());
+    assert!(Instant::NONE.and_then(|i| i.checked_add(sec)).is_none());
+
+    assert_eq!(now.or(Instant::NONE), now);
+    assert_eq!(Instant::NONE.or(now), now);
+    assert!(Instant::NONE.or(Instant::NONE).is_none());
+
+    assert_eq!(now.or_else(|| unreachable!()), now);
+    assert_eq!(Instant::NONE.or_else(|| now), now);
+    assert!(Instant::NONE.or_else(|| Instant::NONE).is_none());
+}
+
+#[test]
+fn as_std() {
+    let now = std::time::Instant::now();
+    let instant = Instant::from(now);
+    assert_eq!(instant.as_std(), Some(&now));
+    assert_eq!(instant.as_ref(), &Some(now));
+    assert_eq!(Instant::NONE.as_std(), None);
+    assert_eq!(Instant::NONE.as_ref(), &None);
+}
+
+#[test]
+fn default() {
+    assert!(Instant::default().is_none());
+    assert_eq!(Instant::default(), Instant::NONE);
+    assert!((Instant::default() + Duration::from_secs(1)).is_none());
+}
+
+#[cfg(feature = "mock")]
+#[test]
+fn from_base_offset() {
+    let base = Instant::now();
+    let a = Instant::from_base_offset(base, Duration::from_millis(1_500));
+    let b = Instant::from_base_offset(base, Duration::from_secs(4));
+    assert_eq!(Instant::from_base_offset(base, Duration::ZERO), base);
+    assert_eq!(b.duration_since(a), Duration::from_millis(2_500));
+    assert_eq!(a.duration_since(b), Duration::ZERO);
+    assert_eq!(b - a, Duration::from_millis(2_500));
+    assert_eq!(b.try_duration_since(a), Ok(Duration::from_millis(2_500)));
+    assert!(Instant::from_base_offset(base, Duration::NONE).is_none());
+    assert!(Instant::from_base_offset(Instant::NONE, Duration::ZERO).is_none());
+    assert!(Instant::from_base_offset(base, Duration::MAX).is_none());
+}
+
+#[test]
+fn saturating_add_sub() {
+    let now = Instant::now();
+    let sec = Duration::from_secs(1);
+    assert_eq!(now.saturating_add(sec), now + sec);
+    assert_eq!(now.saturating_sub(sec), now - sec);
+    assert_eq!(now.saturating_add(Duration::ZERO), now);
+    assert_eq!(now.saturating_sub(Duration::ZERO), now);
+
+    // Checked arithmetic overflows, saturating arithmetic clamps to the bound.
+    for dur in [Duration::from_secs(u64::MAX), Duration::MAX] {
+        assert!((now + dur).is_none());
+        let max = now.saturating_add(dur);
+        assert!(max > now + Duration::from_secs(60 * 60 * 24 * 365));
+        assert!((max + Duration::from_nanos(1)).is_none());
+        assert_eq!(max.saturating_add(sec), max);
+
+        assert!((now - dur).is_none());
+        let min = now.saturating_sub(dur);
+        assert!(min < now);
+        assert!((min - Duration::from_nanos(1)).is_none());
+        assert_eq!(min.saturating_sub(sec), min);
+    }
+
+    assert!(now.saturating_add(Duration::NONE).is_none());
+    assert!(now.saturating_sub(Duration::NONE).is_none());
+    assert!(Instant::NONE.saturating_add(sec).is_none());
+    assert!(Instant::NONE.saturating_sub(sec).is_none());
+}
+
+#[test]
+fn duration_since_std() {
+    let start = std::time::Instant::now();
+    let later = Instant::from(start) + Duration::from_secs(1);
+    assert_eq!(later.duration_since_std(start), Duration::from_secs(1));
+    assert_eq!(later.duration_since_std(start), later.duration_since(Instant::from(start)));
+    assert_eq!(later.duration_since_std(start), later - start);
+    assert_eq!(Instant::from(start).duration_since_std(start), Duration::ZERO);
+    assert_eq!(
+        (Instant::from(start) - Duration::from_secs(1)).duration_since_std(start),
+        Duration::ZERO
+    );
+    assert_eq!(Instant::NONE.duration_since_std(start), Duration::ZERO);
+}
+
+#[test]
+fn into_option() {
+    let now = std::time::Instant::now();
+    let instant: Instant = Some(now).into();
+    assert_eq!(instant, Instant::from(now));
+    let opt: Option<std::time::Instant> = instant.into();
+    assert_eq!(opt, Some(now));
+    assert_eq!(Option::<std::time::Instant>::from(Instant::NONE), None);
+    let instant: Instant = None.into();
+    assert!(instant.is_none());
+}
+
+#[test]
+fn duration_add_instant() {
+    let sec = Duration::from_secs(1);
+    assert_duration_almost_eq!(
+        sec + Instant::now(),
+        Instant::now() + sec,
+        Duration::from_millis(100)
+    );
+
+    let now = Instant::now();
+    assert_eq!(sec + now, now + sec);
+    assert_eq!(Duration::ZERO + now, now);
+    let std_now = std::time::Instant::now();
+    assert_eq!(sec + std_now, std_now + sec);
+    assert_eq!(sec + std_now, Instant::from(std_now) + sec);
+
+    assert!((Duration::MAX + now).is_none());
+    assert!((Duration::MAX + std_now).is_none());
+    assert!((Duration::NONE + now).is_none());
+    assert!((sec + Instant::NONE).is_none());
+}
+
+#[test]
+fn max_min_some() {
+    let now = Instant::now();
+    let later = now + Duration::from_secs(1);
+    assert_eq!(now.max_some(later), later);
+    assert_eq!(later.max_some(now), later);
+    assert_eq!(now.min_some(later), now);
+    assert_eq!(later.min_some(now), now);
+    assert_eq!(now.max_some(now), now);
+
+    // Diverges from `Ord`, which treats `None` as the earliest instant.
+    assert_eq!(now.min(Instant::NONE), Instant::NONE);
+    assert_eq!(now.min_some(Instant::NONE), now);
+    assert_eq!(Instant::NONE.min_some(now), now);
+    assert_eq!(now.max_some(Instant::NONE), now);
+    assert_eq!(Instant::NONE.max_some(now), now);
+    assert!(Instant::NONE.max_some(Instant::NONE).is_none());
+    assert!(Instant::NONE.min_some(Instant::NONE).is_none());
+}
+
+#[test]
+fn std_instant_math() {
+    let a = std::time::Instant::now();
+    let b = Instant::now();
+    let dur = b - a;
+    assert_eq!(b.duration_since(Instant::from(a)), dur);
+    assert_duration_almost_eq!(a + dur, b, Duration::DEFAULT_TEST_TOLERANCE);
+    assert_duration_almost_eq!(b - dur, Instant::from(a), Duration::DEFAULT_TEST_TOLERANCE);
+
+    let second = Duration::new(1, 0);
+    assert_eq!((a + second) - a, second);
+    assert_eq!(a - (a - second), second);
+    assert_eq!(a - (a + second), Duration::ZERO);
+    assert_eq!(a - Instant::NONE, Duration::ZERO);
+
+    // Will not panic on overflow.
+    assert!((a + Duration::from_secs(u64::MAX)).is_none());
+    assert!((a - Duration::from_secs(u64::MAX)).is_none());
+    assert!((a + Duration::NONE).is_none());
+    assert!((a - Duration::NONE).is_none());
+}
+
+#[test]
+fn checked_duration_since_std() {
+    let std_now = std::time::Instant::now();
+    let std_later = std_now + std::time::Duration::from_millis(1_500);
+    let now = Instant::from(std_now);
+    let later = Instant::from(std_later);
+    assert_eq!(later.checked_duration_since_std(now), std_later.checked_duration_since(std_now));
+    assert_eq!(now.checked_duration_since_std(later), std_now.checked_duration_since(std_later));
+    assert_eq!(now.checked_duration_since_std(now), std_now.checked_duration_since(std_now));
+    assert_eq!(
+        later.checked_duration_since_std(now),
+        Some(std::time::Duration::from_millis(1_500))
+    );
+
+    assert_eq!(Instant::NONE.checked_duration_since_std(now), None);
+    assert_eq!(now.checked_duration_since_std(Instant::NONE), None);
+}
+
+#[test]
+fn resolution() {
+    let resolution = Instant::resolution();
+    assert!(resolution.is_some());
+    assert!(resolution > Duration::ZERO);
+    assert!(resolution <= Duration::from_secs(1));
+    assert_eq!(Instant::resolution(), resolution);
+    if cfg!(all(target_os = "linux", not(miri))) {
+        assert_eq!(resolution, Duration::from_nanos(1));
+    }
+}
+
 // https://github.com/rust-lang/rust/blob/1.63.0/library/std/src/time/tests.rs
 mod std_tests {
     #![allow(clippy::eq_op)]
 
-    use easytime::{Duration, Instant};
-
-    macro_rules! assert_almost_eq {
-        ($a:expr, $b:expr) => {{
-            let (a, b) = ($a, $b);
-            if a != b {
-                let (a, b) = if a > b { (a, b) } else { (b, a) };
-                assert!(
-                    a - Duration::from_micros(1) <= b,
-                    "{:?} is not almost equal to {:?}",
-                    a,
-                    b
-                );
-            }
-        }};
-    }
+    use easytime::{assert_duration_almost_eq, Duration, Instant};
 
     #[test]
     fn none() {
@@ -75,11 +308,11 @@ mod std_tests {
         println!("b: {b:?}");
         let dur = b.duration_since(a);
         println!("dur: {dur:?}");
-        assert_almost_eq!(b - dur, a);
-        assert_almost_eq!(a + dur, b);
+        assert_duration_almost_eq!(b - dur, a, Duration::DEFAULT_TEST_TOLERANCE);
+        assert_duration_almost_eq!(a + dur, b, Duration::DEFAULT_TEST_TOLERANCE);
 
         let second = Duration::new(1, 0);
-        assert_almost_eq!(a - second + second, a);
+        assert_duration_almost_eq!(a - second + second, a, Duration::DEFAULT_TEST_TOLERANCE);
 
         // checked_add_duration will not panic on overflow
         let mut maybe_t = Instant::now();
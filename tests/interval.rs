@@ -0,0 +1,57 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![cfg(feature = "std")]
+
+use easytime::{Duration, Instant, Interval};
+
+#[test]
+fn tick() {
+    let start = Instant::now();
+    let secs = |s| start + Duration::from_secs(s);
+    let mut interval = Interval::new_at(start, Duration::from_secs(1));
+    assert!(!interval.tick(start));
+    assert!(!interval.tick(start + Duration::from_millis(999)));
+    assert!(interval.tick(secs(1)));
+    assert!(!interval.tick(secs(1)));
+    assert!(!interval.tick(start + Duration::from_millis(1_999)));
+    assert!(interval.tick(start + Duration::from_millis(2_500)));
+    // Missed ticks are skipped, not fired in a burst.
+    assert!(interval.tick(secs(10)));
+    assert!(!interval.tick(start + Duration::from_millis(10_999)));
+    assert!(interval.tick(secs(11)));
+    assert!(!interval.is_inert());
+    assert!(!interval.tick(Instant::NONE));
+    assert!(!interval.is_inert());
+}
+
+#[test]
+fn inert() {
+    let start = Instant::now();
+
+    let mut interval = Interval::new_at(start, Duration::ZERO);
+    assert!(interval.is_inert());
+    assert!(!interval.tick(start + Duration::from_secs(1)));
+
+    let mut interval = Interval::new_at(start, Duration::NONE);
+    assert!(interval.is_inert());
+    assert!(!interval.tick(start + Duration::from_secs(1)));
+
+    let mut interval = Interval::new_at(Instant::NONE, Duration::from_secs(1));
+    assert!(interval.is_inert());
+    assert!(!interval.tick(start));
+
+    // The deadline overflows.
+    let mut interval = Interval::new_at(start, Duration::from_secs(u64::MAX));
+    assert!(interval.is_inert());
+    assert!(!interval.tick(start));
+
+    let period = Duration::from_secs(u64::MAX / 4);
+    let mut interval = Interval::new_at(start, period);
+    let mut now = start;
+    while !interval.is_inert() {
+        now += period;
+        assert!(now.is_some());
+        assert!(interval.tick(now));
+    }
+    assert!(!interval.tick(now));
+}
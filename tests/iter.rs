@@ -0,0 +1,50 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use easytime::{Duration, DurationIteratorExt as _, TryFromTimeErrorKind};
+
+#[test]
+fn try_sum() {
+    assert_eq!([].iter().copied().try_sum(), Ok(Duration::ZERO));
+    assert_eq!(
+        [Duration::from_secs(1), Duration::from_millis(500), Duration::new(0, 1)]
+            .iter()
+            .copied()
+            .try_sum(),
+        Ok(Duration::new(1, 500_000_001))
+    );
+    assert_eq!([Duration::MAX, Duration::ZERO].iter().copied().try_sum(), Ok(Duration::MAX));
+
+    let err = [Duration::from_secs(1), Duration::NONE, Duration::from_secs(1)]
+        .iter()
+        .copied()
+        .try_sum()
+        .unwrap_err();
+    assert_eq!(err.kind(), TryFromTimeErrorKind::Unknown);
+
+    let err = [Duration::MAX, Duration::new(0, 1)].iter().copied().try_sum().unwrap_err();
+    assert_eq!(err.kind(), TryFromTimeErrorKind::Overflow);
+
+    // short-circuits on the first error
+    let durations = [Duration::NONE, Duration::from_secs(1)];
+    let mut iter = durations.iter().copied();
+    assert!(iter.by_ref().try_sum().is_err());
+    assert_eq!(iter.next(), Some(Duration::from_secs(1)));
+}
+
+#[test]
+fn min_max_some() {
+    let ms = Duration::from_millis;
+    assert_eq!([].iter().copied().min_some(), None);
+    assert_eq!([].iter().copied().max_some(), None);
+    assert_eq!([Duration::NONE, Duration::NONE].iter().copied().min_some(), None);
+    assert_eq!([Duration::NONE, Duration::NONE].iter().copied().max_some(), None);
+
+    let latencies = [ms(30), Duration::NONE, ms(10), ms(50), Duration::NONE];
+    assert_eq!(latencies.iter().copied().min(), Some(Duration::NONE));
+    assert_eq!(latencies.iter().copied().min_some(), Some(ms(10)));
+    assert_eq!(latencies.iter().copied().max_some(), Some(ms(50)));
+
+    let latencies = [Duration::NONE, Duration::ZERO, Duration::MAX];
+    assert_eq!(latencies.iter().copied().min_some(), Some(Duration::ZERO));
+    assert_eq!(latencies.iter().copied().max_some(), Some(Duration::MAX));
+}
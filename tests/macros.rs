@@ -0,0 +1,65 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::time;
+
+use easytime::{assert_duration_almost_eq, Duration};
+
+#[test]
+fn almost_eq() {
+    let us = Duration::from_micros(1);
+    let d = Duration::from_secs(1);
+    assert_duration_almost_eq!(d, d, Duration::ZERO);
+    assert_duration_almost_eq!(d, d + us, us);
+    assert_duration_almost_eq!(d + us, d, us);
+    assert_duration_almost_eq!(d, d + us, time::Duration::from_micros(1), "with {}", "message");
+
+    let d = time::Duration::from_secs(1);
+    assert_duration_almost_eq!(d, d + time::Duration::from_micros(1), us);
+    assert_duration_almost_eq!(d + time::Duration::from_micros(1), d, us,);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn almost_eq_instant() {
+    use easytime::Instant;
+
+    let us = Duration::from_micros(1);
+    let now = Instant::now();
+    assert_duration_almost_eq!(now, now + us, us);
+    assert_duration_almost_eq!(now + us, now, us);
+
+    let now = std::time::Instant::now();
+    assert_duration_almost_eq!(now, now + time::Duration::from_micros(1), us);
+}
+
+#[test]
+#[should_panic = "`left` is almost equal to `right`"]
+fn not_almost_eq() {
+    let d = Duration::from_secs(1);
+    assert_duration_almost_eq!(d, d + Duration::from_nanos(1_001), Duration::from_micros(1));
+}
+
+#[test]
+#[should_panic = "tolerance: Some(1µs): custom message 1"]
+fn not_almost_eq_message() {
+    let d = Duration::from_secs(1);
+    assert_duration_almost_eq!(
+        d + Duration::from_secs(1),
+        d,
+        Duration::from_micros(1),
+        "custom message {}",
+        1
+    );
+}
+
+#[test]
+#[should_panic = "`left` is almost equal to `right`"]
+fn not_almost_eq_none() {
+    assert_duration_almost_eq!(Duration::NONE, Duration::NONE, Duration::MAX);
+}
+
+#[test]
+#[should_panic = "`left` is almost equal to `right`"]
+fn not_almost_eq_none_tolerance() {
+    assert_duration_almost_eq!(Duration::ZERO, Duration::ZERO, Duration::NONE);
+}
@@ -0,0 +1,41 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// Checks that conversions between `Duration` and `core::time::Duration` only
+// need `core`, and that the `alloc`-only APIs work without `std`.
+
+#![no_std]
+
+use core::time;
+
+use easytime::{Duration, TryFromTimeErrorKind};
+
+#[test]
+fn core_duration_round_trip() {
+    let core_dur = time::Duration::new(5, 730_023_852);
+    let dur = Duration::from(core_dur);
+    assert_eq!(dur, Duration::new(5, 730_023_852));
+    assert_eq!(time::Duration::try_from(dur), Ok(core_dur));
+    assert_eq!(dur.into_inner(), Some(core_dur));
+
+    let dur: Duration = Some(core_dur).into();
+    assert_eq!(dur, core_dur);
+    let dur: Duration = None.into();
+    assert!(dur.is_none());
+
+    let err = time::Duration::try_from(Duration::NONE).unwrap_err();
+    assert_eq!(err.kind(), TryFromTimeErrorKind::Unknown);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn slice_percentile() {
+    use easytime::DurationSliceExt as _;
+
+    let ms = Duration::from_millis;
+    let latencies = [ms(40), ms(10), Duration::NONE, ms(30), ms(20)];
+    assert_eq!(latencies.percentile(0.0), Some(ms(10)));
+    assert_eq!(latencies.percentile(50.0), Some(ms(25)));
+    assert_eq!(latencies.percentile(90.0), Some(ms(37)));
+    assert_eq!(latencies.percentile(100.0), Some(ms(40)));
+    assert_eq!([ms(5)].percentile(99.0), Some(ms(5)));
+}
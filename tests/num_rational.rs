@@ -0,0 +1,28 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![cfg(feature = "num-rational")]
+
+use easytime::Duration;
+use num_rational::Ratio;
+
+#[test]
+fn mul_ratio() {
+    let durations = [Duration::ZERO, Duration::new(5, 730_023_852), Duration::from_secs(3)];
+    let ratios = [(0, 1), (1, 1), (1, 3), (2, 3), (48_000, 44_100), (90_000, 48_000)];
+    for d in durations {
+        for (numer, denom) in ratios {
+            assert_eq!(d.mul_ratio(Ratio::new(numer, denom)), d.mul_div(numer, denom));
+            assert_eq!(d.mul_ratio(Ratio::new_raw(numer, denom)), d.mul_div(numer, denom));
+        }
+    }
+    // `Ratio::new` reduces the ratio, which does not change the result.
+    assert_eq!(
+        Duration::from_secs(3).mul_ratio(Ratio::new(48_000, 44_100)),
+        Duration::new(3, 265_306_122)
+    );
+    assert_eq!(Duration::MAX.mul_ratio(Ratio::new_raw(u64::MAX, u64::MAX)), Duration::MAX);
+
+    assert!(Duration::MAX.mul_ratio(Ratio::new(2, 1)).is_none());
+    assert!(Duration::from_secs(1).mul_ratio(Ratio::new_raw(1, 0)).is_none());
+    assert!(Duration::NONE.mul_ratio(Ratio::new(1, 1)).is_none());
+}
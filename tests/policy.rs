@@ -0,0 +1,76 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use easytime::{Duration, OverflowPolicy};
+
+#[test]
+fn none() {
+    let dur = Duration::from_secs(1).with_policy(OverflowPolicy::None);
+    assert_eq!(dur.policy(), OverflowPolicy::None);
+    assert_eq!((dur + Duration::from_secs(1)).get(), Duration::from_secs(2));
+    assert_eq!((dur - Duration::from_secs(1)).get(), Duration::ZERO);
+    assert_eq!((dur * 3).get(), Duration::from_secs(3));
+    assert!((dur + Duration::MAX).get().is_none());
+    assert!((dur - Duration::from_secs(2)).get().is_none());
+    assert!((Duration::MAX.with_policy(OverflowPolicy::None) * 2).get().is_none());
+}
+
+#[test]
+fn saturate() {
+    let dur = Duration::from_secs(1).with_policy(OverflowPolicy::Saturate);
+    assert_eq!((dur + Duration::from_secs(1)).get(), Duration::from_secs(2));
+    assert_eq!((dur - Duration::from_secs(1)).get(), Duration::ZERO);
+    assert_eq!((dur * 3).get(), Duration::from_secs(3));
+    assert_eq!((dur + Duration::MAX).get(), Duration::MAX);
+    assert_eq!((dur - Duration::from_secs(2)).get(), Duration::ZERO);
+    assert_eq!((Duration::MAX.with_policy(OverflowPolicy::Saturate) * 2).get(), Duration::MAX);
+
+    // The policy is kept across operations.
+    let res = dur - Duration::from_secs(2) + Duration::from_secs(5);
+    assert_eq!(res.policy(), OverflowPolicy::Saturate);
+    assert_eq!(Duration::from(res), Duration::from_secs(5));
+
+    // `None` operands are not saturated.
+    assert!((dur + Duration::NONE).get().is_none());
+    assert!((Duration::NONE.with_policy(OverflowPolicy::Saturate) * 2).get().is_none());
+}
+
+#[test]
+fn panic_no_overflow() {
+    let dur = Duration::from_secs(1).with_policy(OverflowPolicy::Panic);
+    assert_eq!((dur + Duration::from_secs(1)).get(), Duration::from_secs(2));
+    assert_eq!((dur - Duration::from_secs(1)).get(), Duration::ZERO);
+    assert_eq!((dur * 3).get(), Duration::from_secs(3));
+    assert!((dur - Duration::NONE).get().is_none());
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic = "overflow when adding durations"]
+fn panic_add() {
+    let _ = Duration::from_secs(1).with_policy(OverflowPolicy::Panic) + Duration::MAX;
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic = "overflow when subtracting durations"]
+fn panic_sub() {
+    let _ = Duration::from_secs(1).with_policy(OverflowPolicy::Panic) - Duration::from_secs(2);
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic = "overflow when multiplying duration by scalar"]
+fn panic_mul() {
+    let _ = Duration::MAX.with_policy(OverflowPolicy::Panic) * 2;
+}
+
+#[cfg(not(debug_assertions))]
+#[test]
+fn panic_release() {
+    // Overflow returns `None` instead of panicking if debug assertions are disabled.
+    let dur = Duration::from_secs(1).with_policy(OverflowPolicy::Panic);
+    assert!((dur + Duration::MAX).get().is_none());
+    assert!((dur - Duration::from_secs(2)).get().is_none());
+    assert!((Duration::MAX.with_policy(OverflowPolicy::Panic) * 2).get().is_none());
+    assert_eq!((dur * 2).policy(), OverflowPolicy::Panic);
+}
@@ -0,0 +1,66 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![cfg(feature = "postgres")]
+
+use bytes::BytesMut;
+use easytime::Duration;
+use postgres_types::{FromSql, IsNull, ToSql, Type};
+
+fn encode(d: Duration) -> Option<Vec<u8>> {
+    let mut buf = BytesMut::new();
+    match d.to_sql_checked(&Type::INTERVAL, &mut buf).unwrap() {
+        IsNull::Yes => None,
+        IsNull::No => Some(buf.to_vec()),
+    }
+}
+
+fn decode(raw: Option<&[u8]>) -> Result<Duration, Box<dyn std::error::Error + Sync + Send>> {
+    Duration::from_sql_nullable(&Type::INTERVAL, raw)
+}
+
+fn interval(micros: i64, days: i32, months: i32) -> Vec<u8> {
+    let mut raw = micros.to_be_bytes().to_vec();
+    raw.extend_from_slice(&days.to_be_bytes());
+    raw.extend_from_slice(&months.to_be_bytes());
+    raw
+}
+
+#[test]
+fn round_trip() {
+    for d in [
+        Duration::NONE,
+        Duration::ZERO,
+        Duration::new(5, 730_023_000),
+        Duration::from_secs(60 * 60 * 24 * 365),
+        Duration::from_micros(i64::MAX as u64),
+    ] {
+        assert_eq!(decode(encode(d).as_deref()).unwrap(), d);
+    }
+    // Sub-microsecond precision is truncated.
+    let raw = encode(Duration::new(5, 730_023_852));
+    assert_eq!(decode(raw.as_deref()).unwrap(), Duration::new(5, 730_023_000));
+}
+
+#[test]
+fn layout() {
+    assert_eq!(encode(Duration::NONE), None);
+    assert_eq!(encode(Duration::new(1, 500_000_000)), Some(interval(1_500_000, 0, 0)));
+    assert_eq!(decode(Some(&interval(1, 2, 0))).unwrap(), Duration::new(2 * 86_400, 1_000));
+    assert!(<Duration as ToSql>::accepts(&Type::INTERVAL));
+    assert!(!<Duration as ToSql>::accepts(&Type::INT8));
+    assert!(!<Duration as FromSql<'_>>::accepts(&Type::INT8));
+}
+
+#[test]
+fn errors() {
+    let mut buf = BytesMut::new();
+    assert!(Duration::from_micros(i64::MAX as u64 + 1)
+        .to_sql_checked(&Type::INTERVAL, &mut buf)
+        .is_err());
+    assert!(Duration::ZERO.to_sql_checked(&Type::INT8, &mut buf).is_err());
+
+    assert!(decode(Some(&interval(0, 0, 1))).is_err());
+    assert!(decode(Some(&interval(-1, 0, 0))).is_err());
+    assert!(decode(Some(&interval(1, -1, 0))).is_err());
+    assert!(decode(Some(&[0; 8])).is_err());
+}
@@ -0,0 +1,36 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![cfg(feature = "rand")]
+
+use easytime::{Duration, DurationRange};
+use rand::{distributions::Distribution as _, rngs::StdRng, SeedableRng as _};
+
+#[test]
+fn sample() {
+    let mut rng = StdRng::seed_from_u64(0);
+
+    let (min, max) = (Duration::new(1, 999_999_000), Duration::new(2, 1_000));
+    let range = DurationRange::new(min, max).unwrap();
+    for _ in 0..1000 {
+        let d = range.sample(&mut rng);
+        assert!(d.is_some());
+        assert!(min <= d && d <= max, "{d:?}");
+    }
+
+    let range = DurationRange::new(Duration::ZERO, Duration::MAX).unwrap();
+    for _ in 0..1000 {
+        assert!(range.sample(&mut rng).is_some());
+    }
+
+    let one_sec = Duration::from_secs(1);
+    let range = DurationRange::new(one_sec, one_sec).unwrap();
+    assert_eq!(range.sample(&mut rng), one_sec);
+}
+
+#[test]
+fn new() {
+    assert!(DurationRange::new(Duration::from_secs(2), Duration::from_secs(1)).is_none());
+    assert!(DurationRange::new(Duration::NONE, Duration::from_secs(1)).is_none());
+    assert!(DurationRange::new(Duration::from_secs(1), Duration::NONE).is_none());
+    assert!(DurationRange::new(Duration::NONE, Duration::NONE).is_none());
+}
@@ -0,0 +1,53 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![cfg(feature = "std")]
+
+use easytime::{Duration, Instant, RateCounter};
+
+#[test]
+fn rate_per_sec() {
+    let start = Instant::now();
+    let at = |millis| start + Duration::from_millis(millis);
+    let mut counter = RateCounter::new(Duration::from_secs(10));
+    for millis in [0, 250, 500, 750, 1_000, 1_250] {
+        counter.record(at(millis));
+    }
+    let window = Duration::from_secs(1);
+
+    // The window is `(now - window, now]`.
+    assert_eq!(counter.rate_per_sec(window, at(1_000)), Some(4.0));
+    assert_eq!(counter.rate_per_sec(window, at(1_001)), Some(4.0));
+    assert_eq!(counter.rate_per_sec(window, at(1_250)), Some(4.0));
+    assert_eq!(counter.rate_per_sec(window, at(1_999)), Some(2.0));
+    assert_eq!(counter.rate_per_sec(window, at(2_250)), Some(0.0));
+    // Events after `now` are not counted.
+    assert_eq!(counter.rate_per_sec(window, at(500)), Some(3.0));
+    assert_eq!(counter.rate_per_sec(Duration::from_millis(500), at(1_250)), Some(4.0));
+    assert_eq!(counter.rate_per_sec(Duration::from_secs(2), at(1_250)), Some(3.0));
+
+    assert_eq!(counter.rate_per_sec(Duration::ZERO, at(1_000)), None);
+    assert_eq!(counter.rate_per_sec(Duration::NONE, at(1_000)), None);
+    assert_eq!(counter.rate_per_sec(window, Instant::NONE), None);
+}
+
+#[test]
+fn prune() {
+    let start = Instant::now();
+    let at = |secs| start + Duration::from_secs(secs);
+    let window = Duration::from_secs(10);
+    let mut counter = RateCounter::new(Duration::from_secs(1));
+    assert_eq!(counter.retention(), Duration::from_secs(1));
+    counter.record(at(0));
+    counter.record(at(1));
+    assert_eq!(counter.rate_per_sec(window, at(2)), Some(0.2));
+    counter.record(at(2));
+    // The event at 0s is older than the retention period and has been pruned.
+    assert_eq!(counter.rate_per_sec(window, at(2)), Some(0.2));
+    counter.record(Instant::NONE);
+    assert_eq!(counter.rate_per_sec(window, at(2)), Some(0.2));
+
+    let mut counter = RateCounter::new(Duration::NONE);
+    counter.record(at(0));
+    counter.record(at(5));
+    assert_eq!(counter.rate_per_sec(window, at(5)), Some(0.2));
+}
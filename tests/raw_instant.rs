@@ -0,0 +1,57 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use easytime::{Duration, RawInstant};
+
+#[test]
+fn duration_since() {
+    let at = |ticks, hz| RawInstant::from_ticks(ticks, hz);
+    assert_eq!(at(5, 1).ticks(), 5);
+    assert_eq!(at(5, 1).hz(), 1);
+
+    // 1 Hz
+    assert_eq!(at(10, 1).duration_since(at(3, 1)), Duration::from_secs(7));
+    // 1 kHz
+    assert_eq!(at(1_500, 1_000).duration_since(at(0, 1_000)), Duration::from_millis(1_500));
+    // 32.768 kHz: one tick is 30517.578125 ns, truncated.
+    assert_eq!(at(1, 32_768).duration_since(at(0, 32_768)), Duration::from_nanos(30_517));
+    assert_eq!(at(32_769, 32_768).duration_since(at(0, 32_768)), Duration::new(1, 30_517));
+    // u32::MAX Hz
+    assert_eq!(
+        at(u64::from(u32::MAX), u32::MAX).duration_since(at(0, u32::MAX)),
+        Duration::from_secs(1)
+    );
+
+    // Saturates to zero.
+    assert_eq!(at(3, 1).duration_since(at(10, 1)), Duration::ZERO);
+    assert_eq!(at(3, 1).duration_since(at(3, 1)), Duration::ZERO);
+
+    // Mismatched or zero frequency.
+    assert!(at(10, 1).duration_since(at(3, 1_000)).is_none());
+    assert!(at(10, 0).duration_since(at(3, 0)).is_none());
+
+    // No overflow at the end of the counter.
+    assert_eq!(at(u64::MAX, 1).duration_since(at(0, 1)), Duration::from_secs(u64::MAX));
+    assert_eq!(
+        at(u64::MAX, u32::MAX).duration_since(at(0, u32::MAX)),
+        Duration::from_secs(u64::MAX / u64::from(u32::MAX))
+    );
+}
+
+#[test]
+fn checked_add_sub() {
+    let at = |ticks| RawInstant::from_ticks(ticks, 1_000);
+    assert_eq!(at(10).checked_add(Duration::from_secs(1)), Some(at(1_010)));
+    assert_eq!(at(10).checked_add(Duration::from_micros(1_999)), Some(at(11)));
+    assert_eq!(at(1_010).checked_sub(Duration::from_secs(1)), Some(at(10)));
+    assert_eq!(at(10).checked_sub(Duration::from_millis(10)), Some(at(0)));
+
+    // Overflow and underflow.
+    assert_eq!(at(u64::MAX).checked_add(Duration::from_millis(1)), None);
+    assert_eq!(at(0).checked_add(Duration::MAX), None);
+    assert_eq!(at(0).checked_sub(Duration::from_millis(1)), None);
+
+    // `None` duration or zero frequency.
+    assert_eq!(at(10).checked_add(Duration::NONE), None);
+    assert_eq!(at(10).checked_sub(Duration::NONE), None);
+    assert_eq!(RawInstant::from_ticks(10, 0).checked_add(Duration::ZERO), None);
+}
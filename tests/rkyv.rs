@@ -0,0 +1,25 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![cfg(feature = "rkyv")]
+
+use easytime::Duration;
+use rkyv::rancor::Error;
+
+#[test]
+fn round_trip() {
+    let values = vec![Duration::NONE, Duration::ZERO, Duration::new(5, 730_023_852), Duration::MAX];
+    let bytes = rkyv::to_bytes::<Error>(&values).unwrap();
+
+    let archived = rkyv::access::<rkyv::Archived<Vec<Duration>>, Error>(&bytes).unwrap();
+    assert_eq!(archived.len(), 4);
+    assert!(archived[0].is_none());
+    let zero = archived[1].as_ref().unwrap();
+    assert_eq!((zero.as_secs(), zero.subsec_nanos()), (0, 0));
+    let d = archived[2].as_ref().unwrap();
+    assert_eq!((d.as_secs(), d.subsec_nanos()), (5, 730_023_852));
+    let max = archived[3].as_ref().unwrap();
+    assert_eq!((max.as_secs(), max.subsec_nanos()), (u64::MAX, 999_999_999));
+
+    let deserialized = rkyv::deserialize::<Vec<Duration>, Error>(archived).unwrap();
+    assert_eq!(deserialized, values);
+}
@@ -0,0 +1,166 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![cfg(all(feature = "serde", feature = "std"))]
+
+use std::time;
+
+use easytime::{
+    serde::{instant_guarded, instant_relative, system_time_unix},
+    Duration, Instant,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Relative {
+    #[serde(with = "instant_relative")]
+    instant: Instant,
+}
+
+#[test]
+fn instant_relative_round_trip() {
+    let reference = Instant::now();
+    for instant in [
+        reference,
+        reference + Duration::new(5, 730_023_852),
+        reference + Duration::from_secs(60 * 60 * 24),
+        Instant::NONE,
+    ] {
+        let value = Relative { instant };
+        let json =
+            instant_relative::with_reference(reference, || serde_json::to_string(&value)).unwrap();
+        let de: Relative =
+            instant_relative::with_reference(reference, || serde_json::from_str(&json)).unwrap();
+        assert_eq!(de, value);
+    }
+
+    let later = reference + Duration::from_secs(1);
+    let json = instant_relative::with_reference(reference, || {
+        serde_json::to_string(&Relative { instant: later })
+    })
+    .unwrap();
+    assert_eq!(json, r#"{"instant":{"secs":1,"nanos":0}}"#);
+    let de: Relative =
+        instant_relative::with_reference(later, || serde_json::from_str(&json)).unwrap();
+    assert_eq!(de.instant, later + Duration::from_secs(1));
+}
+
+#[test]
+fn instant_relative_errors() {
+    let reference = Instant::now();
+    let later = Relative { instant: reference + Duration::from_secs(1) };
+    let earlier = Relative { instant: reference - Duration::from_secs(1) };
+
+    // No reference instant is set.
+    assert!(serde_json::to_string(&later).is_err());
+    assert!(serde_json::from_str::<Relative>(r#"{"instant":{"secs":1,"nanos":0}}"#).is_err());
+    assert!(
+        instant_relative::with_reference(Instant::NONE, || serde_json::to_string(&later)).is_err()
+    );
+    // `None` does not need a reference instant.
+    assert_eq!(
+        serde_json::to_string(&Relative { instant: Instant::NONE }).unwrap(),
+        r#"{"instant":null}"#
+    );
+    assert!(serde_json::from_str::<Relative>(r#"{"instant":null}"#).unwrap().instant.is_none());
+
+    // Earlier than the reference instant.
+    assert!(
+        instant_relative::with_reference(reference, || serde_json::to_string(&earlier)).is_err()
+    );
+}
+
+#[test]
+fn instant_relative_nested() {
+    let outer = Instant::now();
+    let inner = outer + Duration::from_secs(10);
+    let value = Relative { instant: inner + Duration::from_secs(1) };
+    instant_relative::with_reference(outer, || {
+        let json = instant_relative::with_reference(inner, || serde_json::to_string(&value));
+        assert_eq!(json.unwrap(), r#"{"instant":{"secs":1,"nanos":0}}"#);
+        let json = serde_json::to_string(&value);
+        assert_eq!(json.unwrap(), r#"{"instant":{"secs":11,"nanos":0}}"#);
+    });
+    assert!(serde_json::to_string(&value).is_err());
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Unix {
+    #[serde(with = "system_time_unix")]
+    time: Option<time::SystemTime>,
+}
+
+#[test]
+fn system_time_unix_round_trip() {
+    for time in [
+        Some(time::UNIX_EPOCH),
+        Some(time::UNIX_EPOCH + time::Duration::new(1_700_000_000, 123_456_789)),
+        Some(time::SystemTime::now()),
+        None,
+    ] {
+        let value = Unix { time };
+        let json = serde_json::to_string(&value).unwrap();
+        assert_eq!(serde_json::from_str::<Unix>(&json).unwrap(), value);
+    }
+
+    let value = Unix { time: Some(time::UNIX_EPOCH) };
+    assert_eq!(serde_json::to_string(&value).unwrap(), r#"{"time":{"secs":0,"nanos":0}}"#);
+    let value = Unix { time: None };
+    assert_eq!(serde_json::to_string(&value).unwrap(), r#"{"time":null}"#);
+}
+
+#[test]
+fn system_time_unix_errors() {
+    if let Some(before_epoch) = time::UNIX_EPOCH.checked_sub(time::Duration::from_secs(1)) {
+        assert!(serde_json::to_string(&Unix { time: Some(before_epoch) }).is_err());
+    }
+    let overflow = format!(r#"{{"time":{{"secs":{},"nanos":0}}}}"#, u64::MAX);
+    assert_eq!(serde_json::from_str::<Unix>(&overflow).unwrap(), Unix { time: None });
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Guarded {
+    #[serde(with = "instant_guarded")]
+    instant: Instant,
+}
+
+#[test]
+fn instant_guarded_round_trip() {
+    let reference = Instant::now();
+    for instant in [reference, reference + Duration::new(5, 730_023_852), Instant::NONE] {
+        let value = Guarded { instant };
+        let json =
+            instant_relative::with_reference(reference, || serde_json::to_string(&value)).unwrap();
+        let de: Guarded =
+            instant_relative::with_reference(reference, || serde_json::from_str(&json)).unwrap();
+        assert_eq!(de, value);
+    }
+    assert_eq!(
+        serde_json::to_string(&Guarded { instant: Instant::NONE }).unwrap(),
+        r#"{"instant":null}"#
+    );
+
+    // No reference instant is set.
+    let value = Guarded { instant: reference };
+    assert!(serde_json::to_string(&value).is_err());
+}
+
+#[test]
+fn instant_guarded_nonce_mismatch() {
+    let reference = Instant::now();
+    let value = Guarded { instant: reference + Duration::from_secs(1) };
+    let json =
+        instant_relative::with_reference(reference, || serde_json::to_value(&value)).unwrap();
+    let nonce = json["instant"][0].as_u64().unwrap();
+    assert_ne!(nonce, 0);
+    assert_eq!(json["instant"][1], serde_json::json!({ "secs": 1, "nanos": 0 }));
+
+    // Simulate a value serialized by another process.
+    let mut other = json.clone();
+    other["instant"][0] = nonce.wrapping_add(1).into();
+    let de: Guarded =
+        instant_relative::with_reference(reference, || serde_json::from_value(other)).unwrap();
+    assert!(de.instant.is_none());
+    let de: Guarded =
+        instant_relative::with_reference(reference, || serde_json::from_value(json)).unwrap();
+    assert_eq!(de, value);
+}
@@ -0,0 +1,60 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![cfg(feature = "serde-compact")]
+
+use easytime::{serde::duration_nanos, Duration};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Nanos(#[serde(with = "duration_nanos")] Duration);
+
+const DURATIONS: [Duration; 6] = [
+    Duration::NONE,
+    Duration::ZERO,
+    Duration::new(5, 730_023_852),
+    Duration::from_nanos(u64::MAX),
+    Duration::new(u64::MAX / 1_000_000_000 + 1, 0),
+    Duration::MAX,
+];
+
+#[test]
+fn round_trip_rmp() {
+    for d in DURATIONS {
+        let bytes = rmp_serde::to_vec(&Nanos(d)).unwrap();
+        assert_eq!(rmp_serde::from_slice::<Nanos>(&bytes).unwrap(), Nanos(d));
+    }
+}
+
+#[test]
+fn round_trip_json() {
+    for d in DURATIONS {
+        let json = serde_json::to_string(&Nanos(d)).unwrap();
+        assert_eq!(serde_json::from_str::<Nanos>(&json).unwrap(), Nanos(d));
+    }
+}
+
+#[test]
+fn layout() {
+    // nil
+    assert_eq!(rmp_serde::to_vec(&Nanos(Duration::NONE)).unwrap(), [0xc0]);
+    // uint 32
+    assert_eq!(
+        rmp_serde::to_vec(&Nanos(Duration::from_secs(1))).unwrap(),
+        [0xce, 0x3b, 0x9a, 0xca, 0x00]
+    );
+    // `u128` is only used if the nanoseconds do not fit in `u64`.
+    assert_eq!(rmp_serde::to_vec(&Nanos(Duration::from_nanos(u64::MAX))).unwrap().len(), 9);
+    assert_eq!(rmp_serde::to_vec(&Nanos(Duration::MAX)).unwrap().len(), 18);
+
+    assert_eq!(serde_json::to_string(&Nanos(Duration::new(1, 5))).unwrap(), "1000000005");
+    assert_eq!(serde_json::to_string(&Nanos(Duration::NONE)).unwrap(), "null");
+}
+
+#[test]
+fn out_of_range() {
+    let too_large = Duration::MAX.as_nanos().unwrap() + 1;
+    let bytes = rmp_serde::to_vec(&too_large).unwrap();
+    assert!(rmp_serde::from_slice::<Nanos>(&bytes).is_err());
+    assert!(serde_json::from_str::<Nanos>(&too_large.to_string()).is_err());
+    assert!(serde_json::from_str::<Nanos>("-1").is_err());
+}
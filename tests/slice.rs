@@ -0,0 +1,48 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use easytime::{Duration, DurationSliceExt as _};
+
+#[test]
+fn mean_min_max() {
+    let ms = Duration::from_millis;
+    let latencies = [ms(30), Duration::NONE, ms(10), ms(20), ms(41)];
+    assert_eq!(latencies.mean(), Some(Duration::from_micros(25_250)));
+    assert_eq!(latencies.min_some(), Some(ms(10)));
+    assert_eq!(latencies.max_some(), Some(ms(41)));
+
+    let empty: [Duration; 0] = [];
+    assert_eq!(empty.mean(), None);
+    assert_eq!(empty.min_some(), None);
+    assert_eq!([Duration::NONE, Duration::NONE].mean(), None);
+    assert_eq!([Duration::NONE].max_some(), None);
+
+    // Truncated to the nanosecond.
+    assert_eq!(
+        [Duration::from_nanos(1), Duration::from_nanos(2)].mean(),
+        Some(Duration::from_nanos(1))
+    );
+    // The sum is larger than `Duration::MAX`.
+    assert_eq!([Duration::MAX, Duration::MAX, Duration::MAX].mean(), Some(Duration::MAX));
+    assert_eq!(
+        [Duration::MAX, Duration::ZERO].mean(),
+        Some(Duration::new(u64::MAX / 2, 999_999_999))
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn percentile() {
+    let ms = Duration::from_millis;
+    let latencies = [ms(40), ms(10), Duration::NONE, ms(30), ms(20)];
+    assert_eq!(latencies.percentile(0.0), Some(ms(10)));
+    assert_eq!(latencies.percentile(25.0), Some(ms(17) + Duration::from_micros(500)));
+    assert_eq!(latencies.percentile(50.0), Some(ms(25)));
+    assert_eq!(latencies.percentile(90.0), Some(ms(37)));
+    assert_eq!(latencies.percentile(100.0), Some(ms(40)));
+    assert_eq!([ms(5)].percentile(99.0), Some(ms(5)));
+
+    assert_eq!(latencies.percentile(-1.0), None);
+    assert_eq!(latencies.percentile(100.1), None);
+    assert_eq!(latencies.percentile(f64::NAN), None);
+    assert_eq!([Duration::NONE].percentile(50.0), None);
+}
@@ -0,0 +1,64 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![cfg(feature = "std")]
+
+use std::{thread::sleep, time};
+
+use easytime::{Duration, Stopwatch};
+
+const TICK: time::Duration = time::Duration::from_millis(10);
+
+#[test]
+fn elapsed() {
+    let sw = Stopwatch::start();
+    let a = sw.elapsed();
+    sleep(TICK);
+    let b = sw.elapsed();
+    assert!(a.is_some());
+    assert!(b >= a + TICK);
+}
+
+#[test]
+fn lap() {
+    let mut sw = Stopwatch::start();
+    sleep(TICK);
+    let first = sw.lap();
+    sleep(TICK);
+    let second = sw.lap();
+    assert!(first >= TICK);
+    assert!(second >= TICK);
+    assert!(sw.elapsed() >= first + second);
+}
+
+#[test]
+fn pause_resume() {
+    let mut sw = Stopwatch::start();
+    sleep(TICK);
+    sw.pause();
+    assert!(sw.is_paused());
+    let paused = sw.elapsed();
+    sleep(TICK);
+    assert_eq!(sw.elapsed(), paused);
+    sw.pause();
+    assert_eq!(sw.elapsed(), paused);
+
+    sw.resume();
+    assert!(!sw.is_paused());
+    sleep(TICK);
+    assert!(sw.elapsed() >= paused + TICK);
+}
+
+#[test]
+fn reset() {
+    let mut sw = Stopwatch::start();
+    sleep(TICK);
+    sw.reset();
+    assert!(!sw.is_paused());
+    assert!(sw.elapsed() < TICK * 100);
+
+    sw.pause();
+    sw.reset();
+    assert!(sw.is_paused());
+    assert_eq!(sw.elapsed(), Duration::ZERO);
+    assert_eq!(sw.lap(), Duration::ZERO);
+}
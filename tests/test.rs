@@ -10,6 +10,6 @@ use easytime::*;
 #[cfg_attr(any(not(target_pointer_width = "64"), miri), ignore)] // We set -Z randomize-layout for Miri.
 fn size() {
     assert_eq!(mem::size_of::<Duration>(), 16);
-    assert_eq!(mem::size_of::<TryFromTimeError>(), 0);
+    assert_eq!(mem::size_of::<TryFromTimeError>(), 1);
     assert_eq!(mem::size_of::<Instant>(), 16);
 }
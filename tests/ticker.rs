@@ -0,0 +1,34 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use easytime::{Duration, Ticker};
+
+#[test]
+fn advance() {
+    let step = Duration::from_millis(250);
+    let mut ticker = Ticker::new(step);
+    assert_eq!(ticker.step(), step);
+    assert_eq!(ticker.total(), Duration::ZERO);
+    for n in 1..=1_000 {
+        assert_eq!(ticker.advance(), step * n);
+    }
+    assert_eq!(ticker.total(), Duration::from_secs(250));
+
+    let mut ticker = Ticker::new(Duration::ZERO);
+    assert_eq!(ticker.advance(), Duration::ZERO);
+}
+
+#[test]
+fn overflow() {
+    let step = Duration::from_secs(u64::MAX / 3);
+    let mut ticker = Ticker::new(step);
+    assert_eq!(ticker.advance(), step);
+    assert_eq!(ticker.advance(), step * 2);
+    assert_eq!(ticker.advance(), step * 3);
+    assert!(ticker.advance().is_none());
+    assert!(ticker.total().is_none());
+    // Stays `None` once overflowed.
+    assert!(ticker.advance().is_none());
+
+    let mut ticker = Ticker::new(Duration::NONE);
+    assert!(ticker.advance().is_none());
+}
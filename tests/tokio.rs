@@ -0,0 +1,34 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![cfg(feature = "tokio")]
+
+use std::time;
+
+use easytime::{tokio::TimeoutError, Duration, TryFromTimeErrorKind};
+
+const TICK: Duration = Duration::from_millis(10);
+
+#[tokio::test]
+async fn sleep() {
+    let start = time::Instant::now();
+    easytime::tokio::sleep(TICK).await.unwrap();
+    assert!(start.elapsed() >= TICK);
+
+    let start = time::Instant::now();
+    let err = easytime::tokio::sleep(Duration::NONE).await.unwrap_err();
+    assert_eq!(err.kind(), TryFromTimeErrorKind::Unknown);
+    assert!(start.elapsed() < Duration::from_secs(1));
+}
+
+#[tokio::test]
+async fn timeout() {
+    assert_eq!(easytime::tokio::timeout(Duration::from_secs(10), async { 1 }).await, Ok(1));
+    let res = easytime::tokio::timeout(TICK, easytime::tokio::sleep(Duration::from_secs(10))).await;
+    assert_eq!(res, Err(TimeoutError::Elapsed));
+
+    let mut polled = false;
+    let res = easytime::tokio::timeout(Duration::NONE, async { polled = true }).await;
+    assert_eq!(res, Err(TimeoutError::InvalidDuration));
+    assert!(!polled);
+    assert_eq!(TimeoutError::Elapsed.to_string(), "deadline has elapsed");
+}
@@ -6,7 +6,7 @@
 mod file;
 
 use std::{
-    collections::{BTreeSet, HashSet},
+    collections::{BTreeSet, HashMap, HashSet},
     path::Path,
 };
 
@@ -42,6 +42,20 @@ fn gen_assert_impl() {
         })
         .collect();
 
+    // Collect #[cfg] attributes on out-of-line module declarations in lib.rs,
+    // so that assertions for types in feature-gated modules are also gated.
+    let lib =
+        syn::parse_file(&fs::read_to_string(workspace_root.join("src/lib.rs")).unwrap()).unwrap();
+    let mut mod_cfg: HashMap<String, Vec<syn::Attribute>> = HashMap::new();
+    for item in lib.items {
+        if let syn::Item::Mod(item) = item {
+            if item.content.is_none() {
+                let cfg = item.attrs.into_iter().filter(|attr| attr.path().is_ident("cfg"));
+                mod_cfg.insert(item.ident.to_string(), cfg.collect());
+            }
+        }
+    }
+
     let mut tokens = quote! {};
     let mut visited_types = HashSet::new();
     let mut use_generics_helpers = false;
@@ -49,21 +63,23 @@ fn gen_assert_impl() {
         let s = fs::read_to_string(f).unwrap();
         let mut ast = syn::parse_file(&s).unwrap();
 
-        let module = if f.ends_with("lib.rs") {
-            vec![]
+        let (module, file_cfg) = if f.ends_with("lib.rs") {
+            (vec![], vec![])
         } else {
-            let name = format_ident!("{}", Path::new(f).file_stem().unwrap().to_string_lossy());
-            vec![name.into()]
+            let name = Path::new(f).file_stem().unwrap().to_string_lossy().into_owned();
+            let file_cfg = mod_cfg.get(&name).cloned().unwrap_or_default();
+            (vec![format_ident!("{}", name).into()], file_cfg)
         };
 
         // TODO: assert impl trait returned from public functions
         ItemVisitor::new(module, |item, module| match item {
-            syn::Item::Struct(syn::ItemStruct { vis, ident, generics, .. })
-            | syn::Item::Enum(syn::ItemEnum { vis, ident, generics, .. })
-            | syn::Item::Union(syn::ItemUnion { vis, ident, generics, .. })
-            | syn::Item::Type(syn::ItemType { vis, ident, generics, .. })
+            syn::Item::Struct(syn::ItemStruct { attrs, vis, ident, generics, .. })
+            | syn::Item::Enum(syn::ItemEnum { attrs, vis, ident, generics, .. })
+            | syn::Item::Union(syn::ItemUnion { attrs, vis, ident, generics, .. })
+            | syn::Item::Type(syn::ItemType { attrs, vis, ident, generics, .. })
                 if matches!(vis, syn::Visibility::Public(..)) =>
             {
+                let mut item_tokens = quote! {};
                 let path_string = quote! { #(#module::)* #ident }.to_string().replace(' ', "");
                 visited_types.insert(path_string.clone());
 
@@ -99,43 +115,43 @@ fn gen_assert_impl() {
                         generics.type_params().map(|_| quote! { NotRefUnwindSafe });
                     let not_ref_unwind_safe_generics = quote! { <#lt #(#not_ref_unwind_safe),*> };
                     if NOT_SEND.contains(&path_string.as_str()) {
-                        tokens.extend(quote! {
+                        item_tokens.extend(quote! {
                             assert_not_send!(crate:: #(#module::)* #ident #unit_generics);
                         });
                     } else {
-                        tokens.extend(quote! {
+                        item_tokens.extend(quote! {
                             assert_send::<crate:: #(#module::)* #ident #unit_generics>();
                             assert_send::<crate:: #(#module::)* #ident #not_sync_generics>();
                             assert_not_send!(crate:: #(#module::)* #ident #not_send_generics);
                         });
                     }
                     if NOT_SYNC.contains(&path_string.as_str()) {
-                        tokens.extend(quote! {
+                        item_tokens.extend(quote! {
                             assert_not_sync!(crate:: #(#module::)* #ident #unit_generics);
                         });
                     } else {
-                        tokens.extend(quote! {
+                        item_tokens.extend(quote! {
                             assert_sync::<crate:: #(#module::)* #ident #unit_generics>();
                             assert_sync::<crate:: #(#module::)* #ident #not_send_generics>();
                             assert_not_sync!(crate:: #(#module::)* #ident #not_sync_generics);
                         });
                     }
                     if NOT_UNPIN.contains(&path_string.as_str()) {
-                        tokens.extend(quote! {
+                        item_tokens.extend(quote! {
                             assert_not_unpin!(crate:: #(#module::)* #ident #unit_generics);
                         });
                     } else {
-                        tokens.extend(quote! {
+                        item_tokens.extend(quote! {
                             assert_unpin::<crate:: #(#module::)* #ident #unit_generics>();
                             assert_not_unpin!(crate:: #(#module::)* #ident #not_unpin_generics);
                         });
                     }
                     if NOT_UNWIND_SAFE.contains(&path_string.as_str()) {
-                        tokens.extend(quote! {
+                        item_tokens.extend(quote! {
                             assert_not_unwind_safe!(crate:: #(#module::)* #ident #unit_generics);
                         });
                     } else {
-                        tokens.extend(quote! {
+                        item_tokens.extend(quote! {
                             assert_unwind_safe::<crate:: #(#module::)* #ident #unit_generics>();
                             assert_not_unwind_safe!(
                                 crate:: #(#module::)* #ident #not_unwind_safe_generics
@@ -143,13 +159,13 @@ fn gen_assert_impl() {
                         });
                     }
                     if NOT_REF_UNWIND_SAFE.contains(&path_string.as_str()) {
-                        tokens.extend(quote! {
+                        item_tokens.extend(quote! {
                             assert_not_ref_unwind_safe!(
                                 crate:: #(#module::)* #ident #unit_generics
                             );
                         });
                     } else {
-                        tokens.extend(quote! {
+                        item_tokens.extend(quote! {
                             assert_ref_unwind_safe::<crate:: #(#module::)* #ident #unit_generics>();
                             assert_not_ref_unwind_safe!(
                                 crate:: #(#module::)* #ident #not_ref_unwind_safe_generics
@@ -163,51 +179,66 @@ fn gen_assert_impl() {
                         quote! {}
                     };
                     if NOT_SEND.contains(&path_string.as_str()) {
-                        tokens.extend(quote! {
+                        item_tokens.extend(quote! {
                             assert_not_send!(crate:: #(#module::)* #ident #lt);
                         });
                     } else {
-                        tokens.extend(quote! {
+                        item_tokens.extend(quote! {
                             assert_send::<crate:: #(#module::)* #ident #lt>();
                         });
                     }
                     if NOT_SYNC.contains(&path_string.as_str()) {
-                        tokens.extend(quote! {
+                        item_tokens.extend(quote! {
                             assert_not_sync!(crate:: #(#module::)* #ident #lt);
                         });
                     } else {
-                        tokens.extend(quote! {
+                        item_tokens.extend(quote! {
                             assert_sync::<crate:: #(#module::)* #ident #lt>();
                         });
                     }
                     if NOT_UNPIN.contains(&path_string.as_str()) {
-                        tokens.extend(quote! {
+                        item_tokens.extend(quote! {
                             assert_not_unpin!(crate:: #(#module::)* #ident #lt);
                         });
                     } else {
-                        tokens.extend(quote! {
+                        item_tokens.extend(quote! {
                             assert_unpin::<crate:: #(#module::)* #ident #lt>();
                         });
                     }
                     if NOT_UNWIND_SAFE.contains(&path_string.as_str()) {
-                        tokens.extend(quote! {
+                        item_tokens.extend(quote! {
                             assert_not_unwind_safe!(crate:: #(#module::)* #ident #lt);
                         });
                     } else {
-                        tokens.extend(quote! {
+                        item_tokens.extend(quote! {
                             assert_unwind_safe::<crate:: #(#module::)* #ident #lt>();
                         });
                     }
                     if NOT_REF_UNWIND_SAFE.contains(&path_string.as_str()) {
-                        tokens.extend(quote! {
+                        item_tokens.extend(quote! {
                             assert_not_ref_unwind_safe!(crate:: #(#module::)* #ident #lt);
                         });
                     } else {
-                        tokens.extend(quote! {
+                        item_tokens.extend(quote! {
                             assert_ref_unwind_safe::<crate:: #(#module::)* #ident #lt>();
                         });
                     }
                 }
+
+                let cfg: Vec<_> = file_cfg
+                    .iter()
+                    .chain(attrs.iter().filter(|attr| attr.path().is_ident("cfg")))
+                    .collect();
+                if cfg.is_empty() {
+                    tokens.extend(item_tokens);
+                } else {
+                    tokens.extend(quote! {
+                        #(#cfg)*
+                        {
+                            #item_tokens
+                        }
+                    });
+                }
             }
             _ => {}
         })